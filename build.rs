@@ -0,0 +1,43 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn collect(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if std::env::var_os("CARGO_FEATURE_WEB_UI").is_none() {
+        return;
+    }
+
+    // Embed the output of `npm run build:web` into the native binary
+    let dist = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("dist");
+    println!("cargo:rerun-if-changed={}", dist.display());
+    if !dist.join("index.html").exists() {
+        panic!("The web-ui feature needs the frontend, run `npm run build:web` first.");
+    }
+    let mut files = vec![];
+    collect(&dist, &mut files);
+    files.sort();
+
+    let mut output = String::from("pub static ASSETS: &[(&str, &[u8])] = &[\n");
+    for file in files {
+        let name = file
+            .strip_prefix(&dist)
+            .unwrap()
+            .to_string_lossy()
+            .replace('\\', "/");
+        output += &format!("    ({:?}, include_bytes!({:?})),\n", name, file);
+    }
+    output += "];\n";
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).join("web_assets.rs");
+    fs::write(out, output).unwrap();
+}
@@ -0,0 +1,83 @@
+use std::net::IpAddr;
+use std::path::PathBuf;
+
+#[cfg(not(feature = "web-ui"))]
+const USAGE: &str =
+    "Usage: dsp_seed [--record <file>] [--replay <file>] [--store <file> | --no-store] [--listen <address>]
+       dsp_seed --verify <file> [--seeds <count>] [--random-seed <seed>]";
+#[cfg(feature = "web-ui")]
+const USAGE: &str = "Usage: dsp_seed [--record <file>] [--replay <file>] [--store <file> | --no-store] [--listen <address>] [--http-port <port>]
+       dsp_seed --verify <file> [--seeds <count>] [--random-seed <seed>]";
+
+#[derive(Default)]
+pub struct Args {
+    /// Log every incoming message to this file
+    pub record: Option<PathBuf>,
+    /// Feed the messages of a recording back instead of listening for connections
+    pub replay: Option<PathBuf>,
+    /// Where found seeds are kept, defaults to `store::DEFAULT_PATH`
+    pub store: Option<PathBuf>,
+    pub no_store: bool,
+    /// Compare searching and generating for the game and rule in this file, then exit
+    pub verify: Option<PathBuf>,
+    /// How many random seeds to verify, defaults to `verify::DEFAULT_SEEDS`
+    pub seeds: Option<usize>,
+    /// Picks the verified seeds, to repeat an earlier run
+    pub random_seed: Option<i32>,
+    /// Address to listen on instead of localhost, e.g. `0.0.0.0` for the local network
+    pub listen: Option<IpAddr>,
+    /// Port of the bundled frontend
+    #[cfg(feature = "web-ui")]
+    pub http_port: Option<u16>,
+}
+
+impl Args {
+    pub fn parse() -> Result<Self, String> {
+        let mut args = Self::default();
+        let mut iter = std::env::args().skip(1);
+        while let Some(arg) = iter.next() {
+            let mut value = || {
+                iter.next()
+                    .ok_or_else(|| format!("Missing value for {}.\n{}", arg, USAGE))
+            };
+            match arg.as_str() {
+                "--record" => args.record = Some(value()?.into()),
+                "--replay" => args.replay = Some(value()?.into()),
+                "--store" => args.store = Some(value()?.into()),
+                "--no-store" => args.no_store = true,
+                "--verify" => args.verify = Some(value()?.into()),
+                "--seeds" => {
+                    args.seeds = Some(
+                        value()?
+                            .parse()
+                            .map_err(|_| format!("Invalid seed count.\n{}", USAGE))?,
+                    )
+                }
+                "--listen" => {
+                    args.listen = Some(
+                        value()?
+                            .parse()
+                            .map_err(|_| format!("Invalid address.\n{}", USAGE))?,
+                    )
+                }
+                "--random-seed" => {
+                    args.random_seed = Some(
+                        value()?
+                            .parse()
+                            .map_err(|_| format!("Invalid random seed.\n{}", USAGE))?,
+                    )
+                }
+                #[cfg(feature = "web-ui")]
+                "--http-port" => {
+                    args.http_port = Some(
+                        value()?
+                            .parse()
+                            .map_err(|_| format!("Invalid port.\n{}", USAGE))?,
+                    )
+                }
+                _ => return Err(format!("Unknown argument {}.\n{}", arg, USAGE)),
+            }
+        }
+        Ok(args)
+    }
+}
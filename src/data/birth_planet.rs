@@ -0,0 +1,49 @@
+use super::enums::VeinType;
+use super::star_planets::StarWithPlanets;
+use super::vein::VeinStat;
+use serde::Serialize;
+
+/// Starting conditions on the birth planet
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BirthPlanet {
+    pub index: usize,
+    pub theme_id: i32,
+    pub ocean_type: i32,
+    pub wind: f32,
+    pub luminosity: f32,
+    pub veins: Vec<VeinStat>,
+    /// Sum of the average oil amounts of the other planets in the birth system
+    pub system_oil: f32,
+}
+
+impl BirthPlanet {
+    /// Planets of the birth star must be loaded before calling this
+    pub fn from_star(sp: &StarWithPlanets) -> Option<Self> {
+        let planets = sp.get_planets();
+        let planet = planets.iter().find(|planet| planet.is_birth())?;
+        let theme = planet.get_theme();
+        let system_oil = planets
+            .iter()
+            .filter(|p| p.index != planet.index)
+            .flat_map(|p| p.get_veins())
+            .filter(|vein| vein.vein_type == VeinType::Oil)
+            .fold(0.0, |total, vein| total + vein.stat().avg);
+        Some(Self {
+            index: planet.index,
+            theme_id: theme.id,
+            ocean_type: theme.water_item_id,
+            wind: theme.wind,
+            luminosity: planet.get_luminosity(),
+            veins: planet.get_veins().iter().map(|vein| vein.stat()).collect(),
+            system_oil,
+        })
+    }
+
+    pub fn get_vein(&self, vein_type: &VeinType) -> f32 {
+        self.veins
+            .iter()
+            .filter(|vein| &vein.vein_type == vein_type)
+            .fold(0.0, |total, vein| total + vein.avg)
+    }
+}
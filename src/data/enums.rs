@@ -106,3 +106,13 @@ impl VeinType {
         }
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize, Serialize)]
+pub enum Singularity {
+    TidalLocked,
+    TidalLocked2,
+    TidalLocked4,
+    ClockwiseRotate,
+    LaySide,
+    MultipleSatellites,
+}
@@ -1,8 +1,12 @@
+use super::birth_planet::BirthPlanet;
 use super::star_planets::StarWithPlanets;
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Galaxy<'a> {
     pub seed: i32,
     pub stars: Vec<StarWithPlanets<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birth_planet: Option<BirthPlanet>,
 }
@@ -1,3 +1,4 @@
+pub mod birth_planet;
 pub mod enums;
 pub mod galaxy;
 pub mod game_desc;
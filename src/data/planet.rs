@@ -1,4 +1,4 @@
-use super::enums::{PlanetType, SpectrType, StarType, ThemeDistribute, VeinType};
+use super::enums::{PlanetType, Singularity, SpectrType, StarType, ThemeDistribute, VeinType};
 use super::macros::macros::{lazy_getter, lazy_getter_ref};
 use super::random::DspRandom;
 use super::star::Star;
@@ -274,8 +274,24 @@ impl<'a> Planet<'a> {
         }
     });
 
-    pub fn is_tidal_locked(&self) -> bool {
-        self.get_rotation_period() == self.get_orbital_period()
+    /// Rotations per orbit of a planet in resonance, read back from `get_rotation_period`
+    pub fn get_resonance(&self) -> Option<u32> {
+        let rotation_period = self.get_rotation_period();
+        [1, 2, 4]
+            .into_iter()
+            .find(|ratio| rotation_period == self.get_orbital_period() / (*ratio as f64))
+    }
+
+    /// Does not cover `MultipleSatellites`, which depends on the other planets of the star
+    pub fn has_singularity(&self, singularity: &Singularity) -> bool {
+        match singularity {
+            Singularity::TidalLocked => self.get_resonance() == Some(1),
+            Singularity::TidalLocked2 => self.get_resonance() == Some(2),
+            Singularity::TidalLocked4 => self.get_resonance() == Some(4),
+            Singularity::ClockwiseRotate => self.get_rotation_period() < 0.0,
+            Singularity::LaySide => self.rotation_param < 0.04,
+            Singularity::MultipleSatellites => false,
+        }
     }
 
     lazy_getter!(self, get_orbit_inclination, f32, {
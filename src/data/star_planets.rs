@@ -90,11 +90,7 @@ impl<'a> StarWithPlanets<'a> {
             }
             for vein in planet.get_veins() {
                 if &vein.vein_type == vein_type {
-                    let avg_patches = ((vein.min_patch + vein.max_patch) as f32)
-                        * ((vein.min_group + vein.max_group) as f32)
-                        * ((vein.min_amount + vein.max_amount) as f32)
-                        / 8.0;
-                    count += avg_patches;
+                    count += vein.stat().avg;
                 }
             }
         }
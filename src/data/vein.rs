@@ -31,4 +31,25 @@ impl Vein {
     pub fn new() -> Self {
         Default::default()
     }
+
+    pub fn stat(&self) -> VeinStat {
+        VeinStat {
+            vein_type: self.vein_type.clone(),
+            min: self.min_group * self.min_patch * self.min_amount,
+            max: self.max_group * self.max_patch * self.max_amount,
+            avg: ((self.min_patch + self.max_patch) as f32)
+                * ((self.min_group + self.max_group) as f32)
+                * ((self.min_amount + self.max_amount) as f32)
+                / 8.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VeinStat {
+    pub vein_type: VeinType,
+    pub min: i32,
+    pub max: i32,
+    pub avg: f32,
 }
@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::fs::OpenOptions;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{ErrorKind, Write};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpStream};
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::handshake::server::{
+    Callback, ErrorResponse, Request, Response,
+};
+use tokio_tungstenite::tungstenite::http::StatusCode;
+
+/// The port the frontend connects to
+pub const DEFAULT_PORT: u16 = 62879;
+
+/// The port the bundled frontend is served on
+#[cfg(feature = "web-ui")]
+pub const DEFAULT_HTTP_PORT: u16 = 62880;
+
+const FILE_PREFIX: &str = "dsp_seed_finder_";
+
+/// Written next to the other temporary files so clients can find a server
+/// that had to move away from the default port, one file per running instance
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Handshake {
+    pub listen: IpAddr,
+    pub port: u16,
+    pub token: String,
+    pub pid: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_port: Option<u16>,
+}
+
+impl Handshake {
+    pub fn new(address: SocketAddr) -> Self {
+        Self {
+            listen: address.ip(),
+            port: address.port(),
+            token: generate_token(),
+            pid: std::process::id(),
+            http_port: None,
+        }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        std::env::temp_dir().join(format!("{}{}.json", FILE_PREFIX, self.pid))
+    }
+
+    /// The file is removed again when the returned guard is dropped. It holds
+    /// the token, so on unix only the owner may read it; the temporary
+    /// directory on Windows is per user already.
+    pub fn write(&self) -> Result<HandshakeFile, std::io::Error> {
+        let path = self.path();
+        // a file left by an earlier process with the same pid may have other permissions
+        let _ = std::fs::remove_file(&path);
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        options
+            .open(&path)?
+            .write_all(serde_json::to_string_pretty(self)?.as_bytes())?;
+        Ok(HandshakeFile(path))
+    }
+
+    /// Removes the files of instances that no longer accept connections, which
+    /// is all that is left of an instance that was killed
+    pub fn remove_stale() {
+        let Ok(entries) = std::fs::read_dir(std::env::temp_dir()) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if !name.starts_with(FILE_PREFIX) || !name.ends_with(".json") {
+                continue;
+            }
+            let alive = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|content| serde_json::from_str::<Handshake>(&content).ok())
+                .is_some_and(|handshake| {
+                    let ip = match handshake.listen {
+                        ip if ip.is_unspecified() => Ipv4Addr::LOCALHOST.into(),
+                        ip => ip,
+                    };
+                    let address = (ip, handshake.port).into();
+                    TcpStream::connect_timeout(&address, Duration::from_millis(200)).is_ok()
+                });
+            if !alive {
+                let _ = std::fs::remove_file(&path);
+            }
+        }
+    }
+
+    /// What the bundled frontend needs to connect, see `web::handle`
+    #[cfg(feature = "web-ui")]
+    pub fn config(&self, with_token: bool) -> String {
+        #[derive(Serialize)]
+        struct Config<'a> {
+            port: u16,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            token: Option<&'a str>,
+        }
+        serde_json::to_string(&Config {
+            port: self.port,
+            token: with_token.then_some(self.token.as_str()),
+        })
+        .unwrap()
+    }
+
+    /// Connections must pass `?token=` unless the server is on the default port
+    /// and only reachable from this machine, where the hosted frontend connects
+    /// without reading the handshake file. A wrong token is always rejected.
+    #[allow(clippy::result_large_err)] // the signature tungstenite expects
+    pub fn authorize(
+        &self,
+        request: &Request,
+        response: Response,
+    ) -> Result<Response, ErrorResponse> {
+        let token = request.uri().query().and_then(|query| {
+            query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("token="))
+        });
+        let authorized = match token {
+            Some(token) => token == self.token,
+            None => self.port == DEFAULT_PORT && self.listen.is_loopback(),
+        };
+        if authorized {
+            Ok(response)
+        } else {
+            let mut error = ErrorResponse::new(Some("Invalid token".to_string()));
+            *error.status_mut() = StatusCode::UNAUTHORIZED;
+            Err(error)
+        }
+    }
+}
+
+pub struct HandshakeFile(PathBuf);
+
+impl HandshakeFile {
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for HandshakeFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+impl Callback for &Handshake {
+    fn on_request(self, request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+        self.authorize(request, response)
+    }
+}
+
+/// Binds `port` on `ip`, or any free port if it is already in use
+pub async fn bind(ip: IpAddr, port: u16) -> Result<TcpListener, std::io::Error> {
+    match TcpListener::bind((ip, port)).await {
+        Err(err) if err.kind() == ErrorKind::AddrInUse => {
+            let listener = TcpListener::bind((ip, 0)).await?;
+            println!(
+                "Port {} is in use, using {} instead.",
+                port,
+                listener.local_addr()?.port()
+            );
+            Ok(listener)
+        }
+        result => result,
+    }
+}
+
+fn generate_token() -> String {
+    // RandomState is seeded from the OS for every instance
+    let mut token = String::new();
+    for _ in 0..2 {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u32(std::process::id());
+        token += &format!("{:016x}", hasher.finish());
+    }
+    token
+}
@@ -0,0 +1,92 @@
+use crate::transform_rules::Rules;
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+
+/// Messages a slow subscriber may fall behind before it starts missing some
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Results replayed to late subscribers, older ones are only in the seed store
+const REPLAYED_RESULTS: usize = 1024;
+
+pub static JOBS: Lazy<Jobs> = Lazy::new(Jobs::default);
+
+/// A running search that other connections can follow
+pub struct Job {
+    pub id: u64,
+    /// Latest results, replayed to late subscribers
+    results: Mutex<VecDeque<String>>,
+    sender: broadcast::Sender<String>,
+    rule: Mutex<Arc<Rules>>,
+    /// Bumped on every rule change so the workers only lock `rule` when needed
+    rule_version: AtomicU64,
+}
+
+impl Job {
+    /// Forwards an already serialized message to every subscriber
+    pub fn publish(&self, message: &str, is_result: bool) {
+        let mut results = self.results.lock().unwrap();
+        if is_result {
+            if results.len() == REPLAYED_RESULTS {
+                results.pop_front();
+            }
+            results.push_back(message.to_owned());
+        }
+        // no subscribers is fine
+        let _ = self.sender.send(message.to_owned());
+    }
+
+    /// Returns the latest results and a receiver for everything after them
+    pub fn subscribe(&self) -> (Vec<String>, broadcast::Receiver<String>) {
+        let results = self.results.lock().unwrap();
+        (results.iter().cloned().collect(), self.sender.subscribe())
+    }
+
+    pub fn rule(&self) -> (u64, Arc<Rules>) {
+        let rule = self.rule.lock().unwrap();
+        (self.rule_version.load(Ordering::SeqCst), rule.clone())
+    }
+
+    pub fn rule_version(&self) -> u64 {
+        self.rule_version.load(Ordering::SeqCst)
+    }
+
+    /// Seeds that were not searched yet are checked against the new rule
+    pub fn update_rule(&self, rule: Rules) {
+        let mut current = self.rule.lock().unwrap();
+        *current = Arc::new(rule);
+        self.rule_version.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[derive(Default)]
+pub struct Jobs {
+    next_id: AtomicU64,
+    jobs: Mutex<HashMap<u64, Arc<Job>>>,
+}
+
+impl Jobs {
+    pub fn create(&self, rule: Rules) -> Arc<Job> {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+        let job = Arc::new(Job {
+            id,
+            results: Mutex::new(VecDeque::new()),
+            sender: broadcast::channel(CHANNEL_CAPACITY).0,
+            rule: Mutex::new(Arc::new(rule)),
+            rule_version: AtomicU64::new(0),
+        });
+        self.jobs.lock().unwrap().insert(id, job.clone());
+        job
+    }
+
+    pub fn get(&self, id: u64) -> Option<Arc<Job>> {
+        self.jobs.lock().unwrap().get(&id).cloned()
+    }
+
+    /// Subscribers are closed once the last reference to the job is dropped
+    pub fn remove(&self, id: u64) {
+        self.jobs.lock().unwrap().remove(&id);
+    }
+}
@@ -1,43 +1,174 @@
 #![cfg(not(target_arch = "wasm32"))]
 
+mod args;
 mod data;
+mod discovery;
+mod jobs;
+mod record;
 mod rules;
+mod store;
 mod transform_rules;
+mod verify;
+#[cfg(feature = "web-ui")]
+mod web;
 mod worldgen;
 
+use args::Args;
 use data::game_desc::GameDesc;
+use discovery::Handshake;
+use jobs::{Job, JOBS};
 use futures_util::lock::Mutex;
-use futures_util::{future, SinkExt, StreamExt, TryStreamExt};
+use futures_util::{future, sink, stream, Sink, SinkExt, Stream, StreamExt, TryStreamExt};
+use record::Recorder;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use store::{Store, StoredSearch};
+use std::collections::{HashMap, HashSet};
+use std::net::Ipv4Addr;
+use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
-use std::sync::Arc;
-use tokio::sync::mpsc;
-use std::time::SystemTime;
-use tokio::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Weak};
+use tokio::sync::{broadcast, mpsc};
+use std::time::{Duration, SystemTime};
+use tokio::net::TcpStream;
 use tokio::runtime::Handle;
-use tokio_tungstenite::accept_async;
-use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::accept_hdr_async;
+use tokio_tungstenite::tungstenite::error::ProtocolError;
+use tokio_tungstenite::tungstenite::{Error as WsError, Message};
 use transform_rules::Rules;
-use worldgen::galaxy_gen::{create_galaxy, find_stars};
+use worldgen::galaxy_gen::{create_galaxy_cancellable, find_stars};
+use worldgen::score_report::{create_score_report, ScoreReport};
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 4)]
 async fn main() -> Result<(), std::io::Error> {
     // Configure larger blocking thread pool
     std::env::set_var("TOKIO_BLOCKING_THREADS", "32");
-    
+
+    let args = match Args::parse() {
+        Ok(args) => args,
+        Err(err) => {
+            println!("{}", err);
+            std::process::exit(2);
+        }
+    };
+    if let Some(path) = &args.verify {
+        let failed = verify::run(
+            path,
+            args.seeds.unwrap_or(verify::DEFAULT_SEEDS),
+            args.random_seed,
+        )?;
+        std::process::exit(if failed == 0 { 0 } else { 1 });
+    }
+    if let Some(path) = &args.replay {
+        return replay(path).await;
+    }
+    let recorder = match &args.record {
+        Some(path) => {
+            println!("Recording to {}.", path.display());
+            Some(Arc::new(Recorder::create(path)?))
+        }
+        None => None,
+    };
+    let store = if args.no_store {
+        None
+    } else {
+        let path = args
+            .store
+            .unwrap_or_else(|| store::DEFAULT_PATH.into());
+        println!("Storing found seeds in {}.", path.display());
+        Some(Arc::new(Store::open(&path)?))
+    };
+
     println!("Starting...");
-    let listener = TcpListener::bind("127.0.0.1:62879").await?;
+    Handshake::remove_stale();
+    let listen = args.listen.unwrap_or(Ipv4Addr::LOCALHOST.into());
+    let listener = discovery::bind(listen, discovery::DEFAULT_PORT).await?;
+    let handshake = Handshake::new(listener.local_addr()?);
+    #[cfg(feature = "web-ui")]
+    let handshake = Handshake {
+        http_port: Some(web::serve(args.http_port.unwrap_or(discovery::DEFAULT_HTTP_PORT), &handshake).await?),
+        ..handshake
+    };
+    let handshake = Arc::new(handshake);
+    // Removed again if main returns. Killing the server leaves it behind for
+    // `Handshake::remove_stale` of the next instance.
+    let file = handshake.write()?;
+    println!("Connection details written to {}.", file.path().display());
     println!("Started.");
-    println!("You may now turn on native mode to search.");
+    if !listen.is_loopback() {
+        println!(
+            "Listening on {}, connections need ?token={}.",
+            listen, handshake.token
+        );
+        // the bundled frontend prints its own address
+        #[cfg(not(feature = "web-ui"))]
+        println!(
+            "Other devices open the page with ?server=<this machine>:{}&token={}.",
+            handshake.port, handshake.token
+        );
+    } else if handshake.port == discovery::DEFAULT_PORT {
+        println!("You may now turn on native mode to search.");
+    } else {
+        println!("Another instance is running, clients need the token from the file above.");
+    }
     while let Ok((stream, _)) = listener.accept().await {
-        tokio::spawn(accept_connection(stream));
+        tokio::spawn(accept_connection(
+            stream,
+            handshake.clone(),
+            recorder.clone(),
+            store.clone(),
+        ));
     }
     Ok(())
 }
 
+async fn replay(path: &std::path::Path) -> Result<(), std::io::Error> {
+    let recorded = record::load(path)?;
+    // Start with the first message instead of waiting for the idle time before it
+    let first = recorded.iter().map(|recorded| recorded.time).min().unwrap_or(0);
+    let mut connections: HashMap<usize, Vec<(u64, String)>> = HashMap::new();
+    for recorded in recorded {
+        connections
+            .entry(recorded.connection)
+            .or_default()
+            .push((recorded.time - first, recorded.message));
+    }
+    println!("Replaying {} connection(s).", connections.len());
+
+    let started = tokio::time::Instant::now();
+    let mut tasks = vec![];
+    for (connection, messages) in connections {
+        let read = stream::iter(messages).then(move |(time, message)| async move {
+            tokio::time::sleep_until(started + Duration::from_millis(time)).await;
+            println!("[{}] > {}", connection, message);
+            Ok::<Message, WsError>(Message::Text(message))
+        });
+        // Outgoing messages are printed instead of sent; the channel closes once every
+        // task holding the writer is done
+        let (tx, mut rx) = mpsc::unbounded_channel::<Message>();
+        let write: Writer = Arc::new(Mutex::new(Box::pin(sink::unfold(
+            tx,
+            |tx, msg: Message| async move {
+                let _ = tx.send(msg);
+                Ok::<_, WsError>(tx)
+            },
+        ))));
+        tasks.push(tokio::spawn(handle_connection(write, read, None, None)));
+        tasks.push(tokio::spawn(async move {
+            while let Some(msg) = rx.recv().await {
+                println!("[{}] < {}", connection, msg);
+            }
+        }));
+    }
+    for task in tasks {
+        task.await?;
+    }
+    Ok(())
+}
+
+type Writer = Arc<Mutex<Pin<Box<dyn Sink<Message, Error = WsError> + Send>>>>;
+
 #[derive(Serialize, Deserialize)]
-#[serde(tag = "type")]
+#[serde(tag = "type", rename_all_fields = "camelCase")]
 enum IncomingMessage {
     Generate {
         game: GameDesc,
@@ -48,21 +179,52 @@ enum IncomingMessage {
         range: (i32, i32),
         concurrency: i32,
         autosave: u64,
+        /// Don't report seeds the store already has for this rule
+        #[serde(default)]
+        dedupe: bool,
+    },
+    ScoreReport {
+        #[serde(flatten)]
+        game: GameDesc,
+    },
+    /// Follow the results and progress of a search started by another connection
+    Subscribe {
+        job_id: u64,
+    },
+    /// Replace the rule of a running search, only allowed for the connection that started it
+    UpdateRule {
+        job_id: u64,
+        rule: Rules,
+    },
+    QuerySeeds {
+        #[serde(default)]
+        game: Option<GameDesc>,
+        #[serde(default)]
+        rule: Option<Rules>,
     },
     Stop,
 }
 
 #[derive(Serialize)]
-#[serde(tag = "type")]
+#[serde(tag = "type", rename_all_fields = "camelCase")]
 enum OutgoingMessage {
+    Started { job_id: u64 },
+    RuleUpdated { job_id: u64 },
+    Error { message: String },
     Result { seed: i32, indexes: Vec<usize> },
     Progress { start: i32, end: i32 },
     Done { start: i32, end: i32 },
+    ScoreReport(ScoreReport),
+    Seeds { searches: Vec<StoredSearch> },
 }
 
 #[derive(Clone)]
 enum InternalMessage {
-    Result { seed: i32, indexes: Vec<usize> },
+    Result {
+        seed: i32,
+        indexes: Vec<usize>,
+        rule: Arc<Rules>,
+    },
     Progress { start: i32, end: i32 },
     ThreadFinished,
 }
@@ -101,28 +263,68 @@ impl FindState {
     }
 }
 
-async fn accept_connection(stream: TcpStream) {
-    let ws_stream = accept_async(stream)
-        .await
-        .expect("Error during websocket handshake");
+async fn accept_connection(
+    stream: TcpStream,
+    handshake: Arc<Handshake>,
+    recorder: Option<Arc<Recorder>>,
+    store: Option<Arc<Store>>,
+) {
+    let ws_stream = match accept_hdr_async(stream, &*handshake).await {
+        Ok(ws_stream) => ws_stream,
+        // `Handshake::remove_stale` of another instance checking that this one is alive
+        Err(WsError::Protocol(ProtocolError::HandshakeIncomplete)) => return,
+        Err(err) => {
+            println!("Rejected connection: {}.", err);
+            return;
+        }
+    };
     let (write, read) = ws_stream.split();
+    handle_connection(Arc::new(Mutex::new(Box::pin(write))), read, recorder, store).await;
+}
 
-    let boxed_write = Arc::new(Mutex::new(write));
+async fn handle_connection<R>(
+    boxed_write: Writer,
+    read: R,
+    recorder: Option<Arc<Recorder>>,
+    store: Option<Arc<Store>>,
+) where
+    R: Stream<Item = Result<Message, WsError>>,
+{
     let stopped = Arc::new(AtomicBool::new(false));
+    let mut owned_jobs = HashSet::new();
+    // Cancellation tokens of the galaxies being generated for this connection
+    let mut generating: Vec<Weak<AtomicBool>> = vec![];
+    let connection = recorder.as_ref().map(|r| r.connect());
 
-    let _ = read
+    let result = read
         .try_for_each(|msg| {
-            if !msg.is_empty() {
+            if msg.is_close() {
+                // Nobody is left to receive the galaxies
+                cancel_all(&mut generating);
+            } else if !msg.is_empty() {
+                if let (Some(recorder), Some(connection)) = (&recorder, connection) {
+                    recorder.record(connection, &msg.to_string());
+                }
                 let msg: IncomingMessage = serde_json::from_str(&msg.to_string()).unwrap();
                 match msg {
                     IncomingMessage::Stop => {
                         println!("Stopping");
                         stopped.store(true, Ordering::SeqCst);
+                        cancel_all(&mut generating);
                     }
                     IncomingMessage::Generate { game } => {
                         let w = boxed_write.clone();
+                        let cancelled = Arc::new(AtomicBool::new(false));
+                        generating.retain(|token| token.strong_count() > 0);
+                        generating.push(Arc::downgrade(&cancelled));
                         tokio::task::spawn_blocking(move || {
-                            let galaxy = create_galaxy(&game);
+                            let Some(galaxy) = create_galaxy_cancellable(&game, &cancelled)
+                            else {
+                                println!("Generation cancelled.");
+                                let message = "Generation cancelled.".to_owned();
+                                reply(&w, OutgoingMessage::Error { message });
+                                return;
+                            };
                             let output = serde_json::to_string(&galaxy).unwrap();
                             let runtime = Handle::current();
                             runtime.block_on(async move {
@@ -130,131 +332,289 @@ async fn accept_connection(stream: TcpStream) {
                             })
                         });
                     }
+                    IncomingMessage::ScoreReport { game } => {
+                        let w = boxed_write.clone();
+                        tokio::task::spawn_blocking(move || {
+                            let report = create_score_report(&game);
+                            let output =
+                                serde_json::to_string(&OutgoingMessage::ScoreReport(report))
+                                    .unwrap();
+                            let runtime = Handle::current();
+                            runtime.block_on(async move {
+                                w.lock().await.send(Message::Text(output)).await.unwrap();
+                            })
+                        });
+                    }
                     IncomingMessage::Find {
                         game,
                         rule,
                         range: (start, end),
                         concurrency,
                         autosave,
+                        dedupe,
                     } => {
-                        println!("Receive search request.");
-                        println!("Concurrency: {}.", concurrency);
-                        let threads = concurrency.min(end - start);
-                        let current_seed = Arc::new(AtomicI32::new(start));
-                        let state = Arc::new(std::sync::Mutex::new(FindState {
-                            progress_end: start,
-                            progress_start: start,
-                            running: threads,
-                            pending_seeds: HashSet::new(),
-                            autosave,
-                            last_notify: SystemTime::now(),
-                        }));
                         stopped.store(false, Ordering::SeqCst);
-                        
-                        // Create channel for communication between threads and async task
-                        let (tx, mut rx) = mpsc::unbounded_channel::<InternalMessage>();
-                        
-                        // Spawn worker threads using tokio blocking pool  
-                        for _ in 0..threads {
-                            let tx = tx.clone();
-                            let mut transformed = transform_rules::transform_rules(rule.clone());
-                            let mut g = game.clone();
-                            let s = state.clone();
-                            let cs = current_seed.clone();
-                            let stop = stopped.clone();
-                            
+                        let job = JOBS.create(rule);
+                        owned_jobs.insert(job.id);
+                        tokio::spawn(find(
+                            boxed_write.clone(),
+                            stopped.clone(),
+                            game,
+                            job,
+                            (start, end),
+                            concurrency,
+                            autosave,
+                            store.clone().map(|store| (store, dedupe)),
+                        ));
+                    }
+                    IncomingMessage::Subscribe { job_id } => {
+                        tokio::spawn(subscribe(boxed_write.clone(), job_id));
+                    }
+                    IncomingMessage::UpdateRule { job_id, rule } => {
+                        let message = match JOBS.get(job_id) {
+                            Some(job) if owned_jobs.contains(&job_id) => {
+                                println!("Updating rule of job {}.", job_id);
+                                job.update_rule(rule);
+                                OutgoingMessage::RuleUpdated { job_id }
+                            }
+                            Some(_) => OutgoingMessage::Error {
+                                message: format!("Job {} was started by another connection.", job_id),
+                            },
+                            None => OutgoingMessage::Error {
+                                message: format!("Job {} not found.", job_id),
+                            },
+                        };
+                        reply(&boxed_write, message);
+                    }
+                    IncomingMessage::QuerySeeds { game, rule } => match &store {
+                        Some(store) => {
+                            let (w, store) = (boxed_write.clone(), store.clone());
                             tokio::task::spawn_blocking(move || {
-                                
-                                const BATCH_SIZE: i32 = 200;
-                                loop {
-                                    // Get a batch of seeds to process
-                                    let batch_start = cs
-                                        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| {
-                                            Some(x + BATCH_SIZE)
-                                        })
-                                        .unwrap();
-                                    let batch_end = (batch_start + BATCH_SIZE).min(end);
-                                    
-                                    if batch_start >= end {
-                                        break;
-                                    }
-                                    
-                                    // Process the batch
-                                    for seed in batch_start..batch_end {
-                                        if stop.load(Ordering::SeqCst) {
-                                            break;
-                                        }
-                                        
-                                        g.seed = seed;
-                                        let star_indexes = find_stars(&g, &mut transformed);
-                                        if !star_indexes.is_empty() {
-                                            let _ = tx.send(InternalMessage::Result { seed, indexes: star_indexes });
-                                        }
-                                    }
-                                    
-                                    // Batch update progress - only acquire lock once per batch
-                                    {
-                                        let mut x = s.lock().unwrap();
-                                        for seed in batch_start..batch_end {
-                                            if let Some((start, end)) = x.add(seed) {
-                                                let _ = tx.send(InternalMessage::Progress { start, end });
-                                            }
-                                        }
-                                    }
-                                    
-                                    if stop.load(Ordering::SeqCst) {
-                                        break;
-                                    }
-                                }
-                                
-                                // Signal thread completion
-                                let _ = tx.send(InternalMessage::ThreadFinished);
+                                let message = match store.query(game.as_ref(), rule.as_ref()) {
+                                    Ok(searches) => OutgoingMessage::Seeds { searches },
+                                    Err(err) => OutgoingMessage::Error {
+                                        message: format!("Failed to read the store: {}.", err),
+                                    },
+                                };
+                                reply(&w, message);
                             });
                         }
-                        
-                        // Drop the original sender so the receiver will know when all threads are done
-                        drop(tx);
-                        
-                        // Spawn async task to handle messages
-                        let w = boxed_write.clone();
-                        let state_for_completion = state.clone();
-                        tokio::spawn(async move {
-                            let mut finished_threads = 0;
-                            
-                            while let Some(msg) = rx.recv().await {
-                                match msg {
-                                    InternalMessage::Result { seed, indexes } => {
-                                        let output = serde_json::to_string(&OutgoingMessage::Result { seed, indexes }).unwrap();
-                                        let _ = w.lock().await.send(Message::Text(output)).await;
-                                    }
-                                    InternalMessage::Progress { start, end } => {
-                                        println!("Processing: {}.", end);
-                                        let output = serde_json::to_string(&OutgoingMessage::Progress { start, end }).unwrap();
-                                        let _ = w.lock().await.send(Message::Text(output)).await;
-                                    }
-                                    InternalMessage::ThreadFinished => {
-                                        finished_threads += 1;
-                                        if finished_threads == threads {
-                                            let (progress_start, progress_end) = {
-                                                let x = state_for_completion.lock().unwrap();
-                                                (x.progress_start, x.progress_end)
-                                            };
-                                            println!("Completed: {}.", progress_end);
-                                            let output = serde_json::to_string(&OutgoingMessage::Done { 
-                                                start: progress_start, 
-                                                end: progress_end 
-                                            }).unwrap();
-                                            let _ = w.lock().await.send(Message::Text(output)).await;
-                                            break;
-                                        }
-                                    }
-                                }
-                            }
-                        });
-                    }
+                        None => {
+                            let message = "The seed store is disabled.".to_owned();
+                            reply(&boxed_write, OutgoingMessage::Error { message });
+                        }
+                    },
                 }
             }
             future::ok(())
         })
         .await;
+    // The connection was lost without a close frame. A replayed recording
+    // just ends, its galaxies are still printed.
+    if result.is_err() {
+        cancel_all(&mut generating);
+    }
+}
+
+fn cancel_all(tokens: &mut Vec<Weak<AtomicBool>>) {
+    for token in tokens.drain(..) {
+        if let Some(cancelled) = token.upgrade() {
+            cancelled.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+fn reply(write: &Writer, message: OutgoingMessage) {
+    let w = write.clone();
+    tokio::spawn(async move {
+        let output = serde_json::to_string(&message).unwrap();
+        let _ = w.lock().await.send(Message::Text(output)).await;
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn find(
+    write: Writer,
+    stopped: Arc<AtomicBool>,
+    game: GameDesc,
+    job: Arc<Job>,
+    (start, end): (i32, i32),
+    concurrency: i32,
+    autosave: u64,
+    store: Option<(Arc<Store>, bool)>,
+) {
+    println!("Receive search request, job {}.", job.id);
+    println!("Concurrency: {}.", concurrency);
+    let threads = concurrency.min(end - start);
+    send(&write, &job, &OutgoingMessage::Started { job_id: job.id }).await;
+    let current_seed = Arc::new(AtomicI32::new(start));
+    let state = Arc::new(std::sync::Mutex::new(FindState {
+        progress_end: start,
+        progress_start: start,
+        running: threads,
+        pending_seeds: HashSet::new(),
+        autosave,
+        last_notify: SystemTime::now(),
+    }));
+
+    // Create channel for communication between threads and async task
+    let (tx, mut rx) = mpsc::unbounded_channel::<InternalMessage>();
+
+    // Spawn worker threads using tokio blocking pool
+    for _ in 0..threads {
+        let tx = tx.clone();
+        let job = job.clone();
+        let (mut rule_version, mut rule) = job.rule();
+        let mut transformed = transform_rules::transform_rules((*rule).clone());
+        let mut g = game.clone();
+        let s = state.clone();
+        let cs = current_seed.clone();
+        let stop = stopped.clone();
+
+        tokio::task::spawn_blocking(move || {
+            const BATCH_SIZE: i32 = 200;
+            loop {
+                // Get a batch of seeds to process
+                let batch_start = cs
+                    .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Some(x + BATCH_SIZE))
+                    .unwrap();
+                let batch_end = (batch_start + BATCH_SIZE).min(end);
+
+                if batch_start >= end {
+                    break;
+                }
+
+                // Process the batch
+                let mut processed = batch_start;
+                for seed in batch_start..batch_end {
+                    if stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if job.rule_version() != rule_version {
+                        (rule_version, rule) = job.rule();
+                        transformed = transform_rules::transform_rules((*rule).clone());
+                    }
+
+                    g.seed = seed;
+                    let star_indexes = find_stars(&g, &mut transformed);
+                    if !star_indexes.is_empty() {
+                        let _ = tx.send(InternalMessage::Result {
+                            seed,
+                            indexes: star_indexes,
+                            rule: rule.clone(),
+                        });
+                    }
+                    processed = seed + 1;
+                }
+
+                // Batch update progress - only acquire lock once per batch,
+                // seeds skipped by Stop are left for the next search
+                {
+                    let mut x = s.lock().unwrap();
+                    for seed in batch_start..processed {
+                        if let Some((start, end)) = x.add(seed) {
+                            let _ = tx.send(InternalMessage::Progress { start, end });
+                        }
+                    }
+                }
+
+                if stop.load(Ordering::SeqCst) {
+                    break;
+                }
+            }
+
+            // Signal thread completion
+            let _ = tx.send(InternalMessage::ThreadFinished);
+        });
+    }
+
+    // Drop the original sender so the receiver will know when all threads are done
+    drop(tx);
+
+    let mut finished_threads = 0;
+    // Results may still arrive for the previous rule after an update
+    let mut store_key: Option<(Arc<Rules>, String)> = None;
+
+    while let Some(msg) = rx.recv().await {
+        match msg {
+            InternalMessage::Result {
+                seed,
+                indexes,
+                rule,
+            } => {
+                if let Some((store, dedupe)) = &store {
+                    let key = match &store_key {
+                        Some((key_rule, key)) if Arc::ptr_eq(key_rule, &rule) => key,
+                        _ => &store_key.insert((rule.clone(), Store::key(&game, &rule))).1,
+                    };
+                    let is_new = store.insert(key, &game, &rule, seed, &indexes);
+                    if *dedupe && !is_new {
+                        continue;
+                    }
+                }
+                send(&write, &job, &OutgoingMessage::Result { seed, indexes }).await;
+            }
+            InternalMessage::Progress { start, end } => {
+                println!("Processing: {}.", end);
+                send(&write, &job, &OutgoingMessage::Progress { start, end }).await;
+            }
+            InternalMessage::ThreadFinished => {
+                finished_threads += 1;
+                if finished_threads == threads {
+                    let (progress_start, progress_end) = {
+                        let x = state.lock().unwrap();
+                        (x.progress_start, x.progress_end)
+                    };
+                    println!("Completed: {}.", progress_end);
+                    let done = OutgoingMessage::Done {
+                        start: progress_start,
+                        end: progress_end,
+                    };
+                    send(&write, &job, &done).await;
+                    break;
+                }
+            }
+        }
+    }
+    JOBS.remove(job.id);
+}
+
+/// Sends a message to the connection that started the job and to its subscribers
+async fn send(write: &Writer, job: &Job, message: &OutgoingMessage) {
+    let output = serde_json::to_string(message).unwrap();
+    job.publish(&output, matches!(message, OutgoingMessage::Result { .. }));
+    let _ = write.lock().await.send(Message::Text(output)).await;
+}
+
+async fn subscribe(write: Writer, job_id: u64) {
+    let (results, mut receiver) = match JOBS.get(job_id) {
+        Some(job) => job.subscribe(),
+        None => {
+            let output = serde_json::to_string(&OutgoingMessage::Error {
+                message: format!("Job {} not found.", job_id),
+            })
+            .unwrap();
+            let _ = write.lock().await.send(Message::Text(output)).await;
+            return;
+        }
+    };
+    println!("Subscribed to job {}.", job_id);
+    for output in results {
+        if write.lock().await.send(Message::Text(output)).await.is_err() {
+            return;
+        }
+    }
+    loop {
+        match receiver.recv().await {
+            Ok(output) => {
+                if write.lock().await.send(Message::Text(output)).await.is_err() {
+                    return;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(count)) => {
+                println!("Subscriber of job {} missed {} messages.", job_id, count);
+            }
+            Err(broadcast::error::RecvError::Closed) => return,
+        }
+    }
 }
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufRead, BufReader, LineWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+#[derive(Serialize, Deserialize)]
+pub struct RecordedMessage {
+    /// Milliseconds since the recording started
+    pub time: u64,
+    pub connection: usize,
+    pub message: String,
+}
+
+/// Appends every incoming message to a JSON lines file
+pub struct Recorder {
+    started: Instant,
+    connections: AtomicUsize,
+    file: Mutex<LineWriter<File>>,
+}
+
+impl Recorder {
+    pub fn create(path: &Path) -> std::io::Result<Self> {
+        Ok(Self {
+            started: Instant::now(),
+            connections: AtomicUsize::new(0),
+            file: Mutex::new(LineWriter::new(File::create(path)?)),
+        })
+    }
+
+    pub fn connect(&self) -> usize {
+        self.connections.fetch_add(1, Ordering::SeqCst)
+    }
+
+    pub fn record(&self, connection: usize, message: &str) {
+        let line = serde_json::to_string(&RecordedMessage {
+            time: self.started.elapsed().as_millis() as u64,
+            connection,
+            message: message.to_owned(),
+        })
+        .unwrap();
+        if let Err(err) = writeln!(self.file.lock().unwrap(), "{}", line) {
+            println!("Failed to record message: {}.", err);
+        }
+    }
+}
+
+pub fn load(path: &Path) -> std::io::Result<Vec<RecordedMessage>> {
+    let mut messages = vec![];
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        messages.push(serde_json::from_str(&line)?);
+    }
+    Ok(messages)
+}
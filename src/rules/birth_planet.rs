@@ -0,0 +1,54 @@
+use crate::data::birth_planet::BirthPlanet;
+use crate::data::enums::VeinType;
+use crate::data::rule::{Condition, Rule};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum BirthPlanetTarget {
+    Vein(VeinType),
+    OceanType,
+    Wind,
+    Luminosity,
+    SystemOil,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleBirthPlanet {
+    pub target: BirthPlanetTarget,
+    pub condition: Condition,
+}
+
+impl Rule for RuleBirthPlanet {
+    fn get_priority(&self) -> i32 {
+        16
+    }
+    fn evaluate(
+        &self,
+        galaxy: &crate::data::galaxy::Galaxy,
+        evaluation: &crate::data::rule::Evaluaton,
+    ) -> Vec<usize> {
+        if evaluation.is_known(0) {
+            return vec![];
+        }
+        let sp = &galaxy.stars[0];
+        // the birth star comes first, so loading it is always safe
+        sp.load_planets();
+        let Some(birth) = BirthPlanet::from_star(sp) else {
+            return vec![];
+        };
+        let value = match &self.target {
+            BirthPlanetTarget::Vein(vein) => birth.get_vein(vein),
+            BirthPlanetTarget::OceanType => birth.ocean_type as f32,
+            BirthPlanetTarget::Wind => birth.wind,
+            BirthPlanetTarget::Luminosity => birth.luminosity,
+            BirthPlanetTarget::SystemOil => birth.system_oil,
+        };
+        if self.condition.eval(value) {
+            vec![0]
+        } else {
+            vec![]
+        }
+    }
+}
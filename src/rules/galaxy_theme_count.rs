@@ -0,0 +1,38 @@
+use crate::data::rule::{Condition, Rule};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleGalaxyThemeCount {
+    pub theme_ids: Vec<i32>,
+    pub condition: Condition,
+}
+
+impl Rule for RuleGalaxyThemeCount {
+    fn get_priority(&self) -> i32 {
+        43
+    }
+    fn evaluate(
+        &self,
+        galaxy: &crate::data::galaxy::Galaxy,
+        evaluation: &crate::data::rule::Evaluaton,
+    ) -> Vec<usize> {
+        let mut count = 0;
+        for sp in &galaxy.stars {
+            // every star is loaded in order, so this is always safe
+            sp.load_planets();
+            count += sp
+                .get_planets()
+                .iter()
+                .filter(|planet| self.theme_ids.contains(&planet.get_theme().id))
+                .count();
+        }
+        // the whole galaxy matches, so it rules out no star
+        if self.condition.eval(count as f32) {
+            return (0..evaluation.get_len())
+                .filter(|index| evaluation.is_unknown(*index))
+                .collect();
+        }
+        vec![]
+    }
+}
@@ -10,62 +10,83 @@ pub struct RuleGasCount {
     pub condition: Condition,
 }
 
-impl Rule for RuleGasCount {
-    fn get_priority(&self) -> i32 {
-        if self.ice.is_some() {
-            41
-        } else {
-            32
-        }
+/// Telling gas and ice giants apart needs their themes
+pub fn giant_priority(by_type: bool) -> i32 {
+    if by_type {
+        41
+    } else {
+        32
     }
-    fn evaluate(
-        &self,
-        galaxy: &crate::data::galaxy::Galaxy,
-        evaluation: &crate::data::rule::Evaluaton,
-    ) -> Vec<usize> {
-        let mut result: Vec<usize> = vec![];
-        if let Some(ice) = self.ice {
-            for (index, sp) in galaxy.stars.iter().take(evaluation.get_len()).enumerate() {
-                let is_safe = sp.is_safe();
-                if !evaluation.is_unknown(index) {
+}
+
+/// Keeps the unknown stars for which `accept(gas, ice)` holds. Without
+/// `by_type` themes are not loaded and every giant is counted as gas.
+pub fn filter_giants(
+    galaxy: &crate::data::galaxy::Galaxy,
+    evaluation: &crate::data::rule::Evaluaton,
+    by_type: bool,
+    accept: impl Fn(usize, usize) -> bool,
+) -> Vec<usize> {
+    let mut result: Vec<usize> = vec![];
+    if by_type {
+        for (index, sp) in galaxy.stars.iter().take(evaluation.get_len()).enumerate() {
+            let is_safe = sp.is_safe();
+            if !evaluation.is_unknown(index) {
+                if !is_safe {
+                    sp.load_planets()
+                }
+                continue;
+            }
+            let mut gas = 0;
+            let mut ice = 0;
+            for planet in sp.get_planets() {
+                if !planet.is_gas_giant() {
                     if !is_safe {
-                        sp.load_planets()
+                        planet.get_theme();
                     }
                     continue;
                 }
-                let mut count = 0;
-                for planet in sp.get_planets() {
-                    if !planet.is_gas_giant() {
-                        if !is_safe {
-                            planet.get_theme();
-                        }
-                        continue;
-                    }
-                    let theme = planet.get_theme();
-                    if (theme.temperature < 0.0) == ice {
-                        count += 1;
-                    }
-                }
-                sp.mark_safe();
-                if self.condition.eval(count as f32) {
-                    result.push(index);
+                if planet.get_theme().temperature < 0.0 {
+                    ice += 1;
+                } else {
+                    gas += 1;
                 }
             }
-        } else {
-            for (index, sp) in galaxy.stars.iter().take(evaluation.get_len()).enumerate() {
-                if evaluation.is_known(index) {
-                    continue;
-                }
-                let planets = sp.get_planets();
-                let targets = planets
-                    .iter()
-                    .filter(|planet| planet.is_gas_giant())
-                    .count();
-                if self.condition.eval(targets as f32) {
-                    result.push(index)
-                }
+            sp.mark_safe();
+            if accept(gas, ice) {
+                result.push(index);
+            }
+        }
+    } else {
+        for (index, sp) in galaxy.stars.iter().take(evaluation.get_len()).enumerate() {
+            if evaluation.is_known(index) {
+                continue;
+            }
+            let giants = sp
+                .get_planets()
+                .iter()
+                .filter(|planet| planet.is_gas_giant())
+                .count();
+            if accept(giants, 0) {
+                result.push(index)
             }
         }
-        result
+    }
+    result
+}
+
+impl Rule for RuleGasCount {
+    fn get_priority(&self) -> i32 {
+        giant_priority(self.ice.is_some())
+    }
+    fn evaluate(
+        &self,
+        galaxy: &crate::data::galaxy::Galaxy,
+        evaluation: &crate::data::rule::Evaluaton,
+    ) -> Vec<usize> {
+        filter_giants(galaxy, evaluation, self.ice.is_some(), |gas, ice| {
+            let count = if self.ice == Some(true) { ice } else { gas };
+            self.condition.eval(count as f32)
+        })
     }
 }
@@ -0,0 +1,40 @@
+use super::gas_count::{filter_giants, giant_priority};
+use crate::data::rule::Condition;
+use crate::data::rule::Rule;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleGiantCount {
+    #[serde(default)]
+    pub total: Option<Condition>,
+    #[serde(default)]
+    pub gas: Option<Condition>,
+    #[serde(default)]
+    pub ice: Option<Condition>,
+}
+
+impl RuleGiantCount {
+    fn by_type(&self) -> bool {
+        self.gas.is_some() || self.ice.is_some()
+    }
+}
+
+impl Rule for RuleGiantCount {
+    fn get_priority(&self) -> i32 {
+        giant_priority(self.by_type())
+    }
+    fn evaluate(
+        &self,
+        galaxy: &crate::data::galaxy::Galaxy,
+        evaluation: &crate::data::rule::Evaluaton,
+    ) -> Vec<usize> {
+        filter_giants(galaxy, evaluation, self.by_type(), |gas, ice| {
+            self.total
+                .as_ref()
+                .is_none_or(|c| c.eval((gas + ice) as f32))
+                && self.gas.as_ref().is_none_or(|c| c.eval(gas as f32))
+                && self.ice.as_ref().is_none_or(|c| c.eval(ice as f32))
+        })
+    }
+}
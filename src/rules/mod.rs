@@ -2,16 +2,21 @@ pub mod and;
 pub mod average_vein_amount;
 pub mod birth;
 pub mod birth_distance;
+pub mod birth_planet;
 pub mod composite;
 pub mod dyson_radius;
+pub mod galaxy_theme_count;
 pub mod gas_count;
 pub mod gas_rate;
+pub mod giant_count;
 pub mod luminosity;
+pub mod nearest_distance;
 pub mod ocean_type;
 pub mod or;
 pub mod planet_count;
 pub mod planet_in_dyson_count;
 pub mod satellite_count;
+pub mod singularity_count;
 pub mod spectr;
 pub mod spectr_distance;
 pub mod star_type;
@@ -0,0 +1,35 @@
+use crate::data::rule::{Condition, Evaluaton, Rule};
+
+/// Distance from the birth star to the nearest other star accepted by `rule`.
+/// Without such a star the distance is infinite, so only `Gt`/`Gte` style conditions pass.
+pub struct RuleNearestDistance {
+    pub rule: Box<dyn Rule + Send>,
+    pub condition: Condition,
+}
+
+impl Rule for RuleNearestDistance {
+    fn get_priority(&self) -> i32 {
+        self.rule.get_priority().max(15)
+    }
+    fn evaluate(
+        &self,
+        galaxy: &crate::data::galaxy::Galaxy,
+        evaluation: &crate::data::rule::Evaluaton,
+    ) -> Vec<usize> {
+        if evaluation.is_known(0) {
+            return vec![];
+        }
+        let nearest = self
+            .rule
+            .evaluate(galaxy, &Evaluaton::new(galaxy.stars.len()))
+            .into_iter()
+            .filter(|index| *index != 0)
+            .map(|index| galaxy.stars[index].star.position.magnitude())
+            .fold(f64::INFINITY, f64::min);
+        if self.condition.eval(nearest as f32) {
+            vec![0]
+        } else {
+            vec![]
+        }
+    }
+}
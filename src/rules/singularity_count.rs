@@ -0,0 +1,51 @@
+use crate::data::enums::Singularity;
+use crate::data::rule::Condition;
+use crate::data::rule::Rule;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleSingularityCount {
+    pub singularities: Vec<Singularity>,
+    pub condition: Condition,
+}
+
+impl Rule for RuleSingularityCount {
+    fn get_priority(&self) -> i32 {
+        35
+    }
+    fn evaluate(
+        &self,
+        galaxy: &crate::data::galaxy::Galaxy,
+        evaluation: &crate::data::rule::Evaluaton,
+    ) -> Vec<usize> {
+        let mut result: Vec<usize> = vec![];
+        for (index, sp) in galaxy.stars.iter().take(evaluation.get_len()).enumerate() {
+            if evaluation.is_known(index) {
+                continue;
+            }
+            let planets = sp.get_planets();
+            let satellites = |index: usize| {
+                planets
+                    .iter()
+                    .filter(|p| p.orbit_around.borrow().is_some_and(|x| x.index == index))
+                    .count()
+            };
+            let targets = planets
+                .iter()
+                .filter(|planet| {
+                    self.singularities
+                        .iter()
+                        .any(|singularity| match singularity {
+                            Singularity::MultipleSatellites => satellites(planet.index) > 1,
+                            _ => planet.has_singularity(singularity),
+                        })
+                })
+                .count();
+            if self.condition.eval(targets as f32) {
+                result.push(index)
+            }
+        }
+        result
+    }
+}
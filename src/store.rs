@@ -0,0 +1,235 @@
+use crate::data::game_desc::GameDesc;
+use crate::transform_rules::Rules;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, LineWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Sender};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_PATH: &str = "dsp_seed_store.jsonl";
+
+/// Galaxy settings and rule shared by the seeds of one search
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Search {
+    /// Hex encoded `Store::key`
+    pub key: String,
+    pub star_count: usize,
+    pub resource_multiplier: f32,
+    pub rule: Rules,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FoundSeed {
+    pub seed: i32,
+    pub indexes: Vec<usize>,
+    /// Unix time in seconds
+    pub found: u64,
+}
+
+/// A search with the seeds it found, as returned by `Store::query`
+#[derive(Serialize)]
+pub struct StoredSearch {
+    #[serde(flatten)]
+    pub search: Search,
+    pub seeds: Vec<FoundSeed>,
+}
+
+/// A line of the store file. The search comes once, before its first seed.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all_fields = "camelCase")]
+enum Record {
+    Search(Search),
+    Seed {
+        key: String,
+        seed: i32,
+        indexes: Vec<usize>,
+        found: u64,
+    },
+}
+
+/// Every seed reported by a search, kept as a JSON lines file across sessions.
+/// Only the seed numbers stay in memory, `query` reads the rest from the file.
+pub struct Store {
+    path: PathBuf,
+    searches: Mutex<HashMap<String, (Search, HashSet<i32>)>>,
+    /// Lines for the writer thread, so searches never wait for the disk
+    lines: Mutex<Sender<String>>,
+}
+
+impl Store {
+    pub fn open(path: &Path) -> std::io::Result<Self> {
+        let mut searches = HashMap::new();
+        if path.exists() {
+            for_each_record(path, |line_number, record| {
+                match record {
+                    Ok(Record::Search(search)) => {
+                        searches
+                            .entry(search.key.clone())
+                            .or_insert_with(|| (search, HashSet::new()));
+                    }
+                    Ok(Record::Seed { key, seed, .. }) => match searches.get_mut(&key) {
+                        Some((_, seeds)) => {
+                            seeds.insert(seed);
+                        }
+                        None => println!(
+                            "Skipping line {} of the store: unknown search {}.",
+                            line_number + 1,
+                            key
+                        ),
+                    },
+                    // keep going, a crash may have cut off the last line
+                    Err(err) => {
+                        println!("Skipping line {} of the store: {}.", line_number + 1, err)
+                    }
+                }
+            })?;
+        }
+
+        let mut file = LineWriter::new(OpenOptions::new().create(true).append(true).open(path)?);
+        let (sender, receiver) = channel::<String>();
+        std::thread::spawn(move || {
+            for line in receiver {
+                if let Err(err) = writeln!(file, "{}", line) {
+                    println!("Failed to store seed: {}.", err);
+                }
+            }
+        });
+        Ok(Self {
+            path: path.to_owned(),
+            searches: Mutex::new(searches),
+            lines: Mutex::new(sender),
+        })
+    }
+
+    /// Identifies the galaxy settings and rule of a search. Uses FNV-1a so the
+    /// key stays the same between builds.
+    pub fn key(game: &GameDesc, rule: &Rules) -> String {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let mut bytes = serde_json::to_vec(rule).unwrap();
+        bytes.extend((game.star_count as u64).to_le_bytes());
+        bytes.extend(game.resource_multiplier.to_le_bytes());
+        for byte in bytes {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        format!("{:016x}", hash)
+    }
+
+    fn write(&self, record: &Record) {
+        let line = serde_json::to_string(record).unwrap();
+        // the writer thread only stops with the process
+        let _ = self.lines.lock().unwrap().send(line);
+    }
+
+    /// Records a found seed, returns false if it was already known for this key
+    pub fn insert(
+        &self,
+        key: &str,
+        game: &GameDesc,
+        rule: &Rules,
+        seed: i32,
+        indexes: &[usize],
+    ) -> bool {
+        let mut searches = self.searches.lock().unwrap();
+        let seeds = match searches.entry(key.to_owned()) {
+            Entry::Occupied(entry) => &mut entry.into_mut().1,
+            Entry::Vacant(entry) => {
+                let search = Search {
+                    key: key.to_owned(),
+                    star_count: game.star_count,
+                    resource_multiplier: game.resource_multiplier,
+                    rule: rule.clone(),
+                };
+                self.write(&Record::Search(search.clone()));
+                &mut entry.insert((search, HashSet::new())).1
+            }
+        };
+        if !seeds.insert(seed) {
+            return false;
+        }
+        let found = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        self.write(&Record::Seed {
+            key: key.to_owned(),
+            seed,
+            indexes: indexes.to_vec(),
+            found,
+        });
+        true
+    }
+
+    /// Searches with the given galaxy settings and rule, each filter is optional.
+    /// Reads the whole file, so call it off the async runtime.
+    pub fn query(
+        &self,
+        game: Option<&GameDesc>,
+        rule: Option<&Rules>,
+    ) -> std::io::Result<Vec<StoredSearch>> {
+        let rule = rule.map(|rule| serde_json::to_string(rule).unwrap());
+        let mut searches: HashMap<String, StoredSearch> = self
+            .searches
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|(search, _)| {
+                game.is_none_or(|game| {
+                    search.star_count == game.star_count
+                        && search.resource_multiplier == game.resource_multiplier
+                })
+            })
+            .filter(|(search, _)| {
+                rule.as_ref()
+                    .is_none_or(|rule| *rule == serde_json::to_string(&search.rule).unwrap())
+            })
+            .map(|(search, _)| {
+                let stored = StoredSearch {
+                    search: search.clone(),
+                    seeds: vec![],
+                };
+                (search.key.clone(), stored)
+            })
+            .collect();
+        if searches.is_empty() {
+            return Ok(vec![]);
+        }
+        for_each_record(&self.path, |_, record| {
+            if let Ok(Record::Seed {
+                key,
+                seed,
+                indexes,
+                found,
+            }) = record
+            {
+                if let Some(search) = searches.get_mut(&key) {
+                    search.seeds.push(FoundSeed {
+                        seed,
+                        indexes,
+                        found,
+                    });
+                }
+            }
+        })?;
+        Ok(searches.into_values().collect())
+    }
+}
+
+/// Parses every non-empty line of the store file, passing its index along
+fn for_each_record(
+    path: &Path,
+    mut f: impl FnMut(usize, serde_json::Result<Record>),
+) -> std::io::Result<()> {
+    for (line_number, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            f(line_number, serde_json::from_str(&line));
+        }
+    }
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use crate::data::enums::Singularity;
 use crate::data::rule::{Condition, Rule};
 use crate::rules;
 use serde::{Deserialize, Serialize};
@@ -21,6 +22,10 @@ pub enum Rules {
     Or {
         rules: Vec<Rules>,
     },
+    NearestDistance {
+        rule: Box<Rules>,
+        condition: Condition,
+    },
     Luminosity(rules::luminosity::RuleLuminosity),
     DysonRadius(rules::dyson_radius::RuleDysonRadius),
     AverageVeinAmount(rules::average_vein_amount::RuleAverageVeinAmount),
@@ -38,6 +43,10 @@ pub enum Rules {
     SpectrDistance(rules::spectr_distance::RuleSpectrDistance),
     GasRate(rules::gas_rate::RuleGasRate),
     PlanetInDysonCount(rules::planet_in_dyson_count::RulePlanetInDysonCount),
+    GiantCount(rules::giant_count::RuleGiantCount),
+    SingularityCount(rules::singularity_count::RuleSingularityCount),
+    BirthPlanet(rules::birth_planet::RuleBirthPlanet),
+    GalaxyThemeCount(rules::galaxy_theme_count::RuleGalaxyThemeCount),
 }
 
 pub fn sort_rules(rules: Vec<Rules>) -> Vec<Box<dyn Rule + Send>> {
@@ -64,11 +73,20 @@ pub fn transform_rules(r: Rules) -> Box<dyn Rule + Send> {
         Rules::Or { rules } => Box::new(rules::or::RuleOr {
             rules: sort_rules(rules),
         }),
+        Rules::NearestDistance { rule, condition } => {
+            Box::new(rules::nearest_distance::RuleNearestDistance {
+                rule: transform_rules(*rule),
+                condition,
+            })
+        }
         Rules::Luminosity(rule) => Box::new(rule),
         Rules::DysonRadius(rule) => Box::new(rule),
         Rules::AverageVeinAmount(rule) => Box::new(rule),
         Rules::Spectr(rule) => Box::new(rule),
-        Rules::TidalLockCount(rule) => Box::new(rule),
+        Rules::TidalLockCount(rule) => Box::new(rules::singularity_count::RuleSingularityCount {
+            singularities: vec![Singularity::TidalLocked],
+            condition: rule.condition,
+        }),
         Rules::OceanType(rule) => Box::new(rule),
         Rules::StarType(rule) => Box::new(rule),
         Rules::GasCount(rule) => Box::new(rule),
@@ -81,5 +99,9 @@ pub fn transform_rules(r: Rules) -> Box<dyn Rule + Send> {
         Rules::SpectrDistance(rule) => Box::new(rule),
         Rules::GasRate(rule) => Box::new(rule),
         Rules::PlanetInDysonCount(rule) => Box::new(rule),
+        Rules::GiantCount(rule) => Box::new(rule),
+        Rules::SingularityCount(rule) => Box::new(rule),
+        Rules::BirthPlanet(rule) => Box::new(rule),
+        Rules::GalaxyThemeCount(rule) => Box::new(rule),
     }
 }
@@ -0,0 +1,66 @@
+use crate::data::game_desc::GameDesc;
+use crate::data::random::DspRandom;
+use crate::data::rule::Evaluaton;
+use crate::transform_rules::{transform_rules, Rules};
+use crate::worldgen::galaxy_gen::{create_galaxy, find_stars};
+use serde::Deserialize;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_SEEDS: usize = 1000;
+
+/// Same shape as a `Find` message, so one can be copied from a recording
+#[derive(Deserialize)]
+struct Request {
+    game: GameDesc,
+    rule: Rules,
+}
+
+/// Checks that searching for seeds agrees with generating the whole galaxy and
+/// evaluating the rule on it. Returns the number of seeds that disagree.
+/// Without `random_seed` different seeds are picked every run.
+pub fn run(path: &Path, count: usize, random_seed: Option<i32>) -> std::io::Result<usize> {
+    let request: Request = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    let random_seed = random_seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos() as i32
+    });
+    println!("Verifying {} seeds, random seed {}.", count, random_seed);
+    let mut rand = DspRandom::new(random_seed);
+
+    // Like a search thread, reuse the game and the rule for every seed
+    let mut search_game = request.game.clone();
+    let mut search_rule = transform_rules(request.rule.clone());
+    let mut failed = 0;
+    for checked in 0..count {
+        let seed = rand.next_i32(100_000_000);
+
+        search_game.seed = seed;
+        let mut found = find_stars(&search_game, &mut search_rule);
+
+        let game = GameDesc {
+            seed,
+            ..request.game.clone()
+        };
+        let galaxy = create_galaxy(&game);
+        let evaluation = Evaluaton::new(galaxy.stars.len());
+        let mut expected = transform_rules(request.rule.clone()).evaluate(&galaxy, &evaluation);
+
+        found.sort_unstable();
+        expected.sort_unstable();
+        if found != expected {
+            failed += 1;
+            println!(
+                "Seed {}: search found {:?}, generation found {:?}.",
+                seed, found, expected
+            );
+        }
+        if (checked + 1) % 100 == 0 {
+            println!("Checked {} seeds.", checked + 1);
+        }
+    }
+    println!("{} of {} seeds disagree.", failed, count);
+    Ok(failed)
+}
@@ -0,0 +1,119 @@
+use crate::discovery::Handshake;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+include!(concat!(env!("OUT_DIR"), "/web_assets.rs"));
+
+/// Must match `base` in vite.config.ts
+const BASE: &str = "/DSP-Seed-Finder/";
+
+/// Served next to the assets so the frontend can find the websocket server
+const CONFIG: &str = "config.json";
+
+/// Starts serving the frontend in the background and returns the port it ended up on
+pub async fn serve(port: u16, handshake: &Handshake) -> Result<u16, std::io::Error> {
+    let listener = crate::discovery::bind(handshake.listen, port).await?;
+    let port = listener.local_addr()?.port();
+    println!("Open http://localhost:{}{} in your browser.", port, BASE);
+    if !handshake.listen.is_loopback() {
+        println!(
+            "Other devices open http://<this machine>:{}{}?token={} instead.",
+            port, BASE, handshake.token
+        );
+    }
+    // (with the token, without it)
+    let configs = Arc::new((handshake.config(true), handshake.config(false)));
+    tokio::spawn(async move {
+        while let Ok((stream, peer)) = listener.accept().await {
+            tokio::spawn(handle(stream, peer, configs.clone()));
+        }
+    });
+    Ok(port)
+}
+
+fn content_type(name: &str) -> &'static str {
+    match name.rsplit('.').next().unwrap_or_default() {
+        "html" => "text/html; charset=utf-8",
+        "js" => "text/javascript",
+        "css" => "text/css",
+        "wasm" => "application/wasm",
+        "json" => "application/json",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "ico" => "image/x-icon",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        _ => "application/octet-stream",
+    }
+}
+
+fn find_asset(name: &str) -> Option<&'static [u8]> {
+    ASSETS
+        .iter()
+        .find(|(asset, _)| *asset == name)
+        .map(|(_, content)| *content)
+}
+
+/// Only pages opened on this machine get the token, others must bring it in the URL
+async fn handle(mut stream: TcpStream, peer: SocketAddr, configs: Arc<(String, String)>) {
+    let mut buffer = vec![0; 8192];
+    let mut len = 0;
+    while !buffer[..len].windows(4).any(|w| w == b"\r\n\r\n") {
+        match stream.read(&mut buffer[len..]).await {
+            Ok(0) | Err(_) => return,
+            Ok(n) => len += n,
+        }
+        if len == buffer.len() {
+            return;
+        }
+    }
+    let request = String::from_utf8_lossy(&buffer[..len]);
+    let mut parts = request.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default();
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+
+    let (status, headers, body): (&str, String, &[u8]) = if method != "GET" && method != "HEAD" {
+        ("405 Method Not Allowed", String::new(), b"")
+    } else if let Some(name) = path.strip_prefix(BASE) {
+        let name = if name.is_empty() { "index.html" } else { name };
+        let config = match name {
+            CONFIG if peer.ip().is_loopback() => Some(configs.0.as_bytes()),
+            CONFIG => Some(configs.1.as_bytes()),
+            _ => None,
+        };
+        match config.or_else(|| find_asset(name)) {
+            Some(content) => (
+                "200 OK",
+                format!("Content-Type: {}\r\n", content_type(name)),
+                content,
+            ),
+            // client side routes are handled by the frontend
+            None if !name.contains('.') => (
+                "200 OK",
+                format!("Content-Type: {}\r\n", content_type("index.html")),
+                find_asset("index.html").unwrap_or_default(),
+            ),
+            None => ("404 Not Found", String::new(), b""),
+        }
+    } else {
+        ("302 Found", format!("Location: {}\r\n", BASE), b"")
+    };
+
+    let head = format!(
+        "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        headers,
+        body.len()
+    );
+    if stream.write_all(head.as_bytes()).await.is_err() {
+        return;
+    }
+    if method != "HEAD" {
+        let _ = stream.write_all(body).await;
+    }
+    let _ = stream.shutdown().await;
+}
@@ -1,4 +1,5 @@
 use super::name_gen::random_name;
+use crate::data::birth_planet::BirthPlanet;
 use crate::data::enums::{SpectrType, StarType};
 use crate::data::galaxy::Galaxy;
 use crate::data::game_desc::GameDesc;
@@ -8,6 +9,7 @@ use crate::data::star::Star;
 use crate::data::star_planets::StarWithPlanets;
 use crate::data::vector3::Vector3;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 fn generate_temp_poses(
     seed: i32,
@@ -119,6 +121,8 @@ fn check_collision(tmp_poses: &Vec<Vector3>, pt: &Vector3, min_dist: f64) -> boo
 }
 
 fn generate_stars<'a>(game_desc: &'a GameDesc) -> Vec<StarWithPlanets<'a>> {
+    // a search reuses the same game for every seed
+    game_desc.habitable_count.set(0);
     let galaxy_seed = game_desc.seed;
 
     let mut rand = DspRandom::new(galaxy_seed);
@@ -193,26 +197,46 @@ fn generate_stars<'a>(game_desc: &'a GameDesc) -> Vec<StarWithPlanets<'a>> {
 }
 
 pub fn create_galaxy<'a>(game_desc: &'a GameDesc) -> Galaxy<'a> {
+    create_galaxy_cancellable(game_desc, &AtomicBool::new(false)).unwrap()
+}
+
+/// Same as `create_galaxy` but gives up between stars once `cancelled` is set.
+/// Veins and gases are generated here too so serializing the galaxy is cheap.
+pub fn create_galaxy_cancellable<'a>(
+    game_desc: &'a GameDesc,
+    cancelled: &AtomicBool,
+) -> Option<Galaxy<'a>> {
     let mut stars = generate_stars(game_desc);
     let mut names: Vec<&str> = vec![];
 
     for sp in stars.iter_mut() {
+        if cancelled.load(Ordering::Relaxed) {
+            return None;
+        }
         let name = random_name(sp.star.name_seed, &sp.star, names.iter());
         sp.name = name;
         names.push(&sp.name);
         sp.load_planets();
+        for planet in sp.get_planets() {
+            planet.get_veins();
+            planet.get_gases();
+        }
     }
 
-    Galaxy {
+    let birth_planet = stars.first().and_then(BirthPlanet::from_star);
+
+    Some(Galaxy {
         seed: game_desc.seed,
         stars,
-    }
+        birth_planet,
+    })
 }
 
 pub fn find_stars(game_desc: &GameDesc, rule: &mut Box<dyn Rule + Send>) -> Vec<usize> {
     let galaxy = Galaxy {
         seed: game_desc.seed,
         stars: generate_stars(game_desc),
+        birth_planet: None,
     };
 
     let evaluation = Evaluaton::new(game_desc.star_count);
@@ -1,2 +1,3 @@
 pub mod galaxy_gen;
 mod name_gen;
+pub mod score_report;
@@ -0,0 +1,48 @@
+use super::galaxy_gen::create_galaxy;
+use crate::data::enums::{SpectrType, VeinType};
+use crate::data::game_desc::GameDesc;
+use serde::Serialize;
+
+/// Galaxy-wide metrics for comparing seeds side by side
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreReport {
+    pub seed: i32,
+    pub unipolar_amount: f32,
+    pub gas_giant_count: usize,
+    pub ice_giant_count: usize,
+    pub ob_star_count: usize,
+    pub total_luminosity: f32,
+    pub rocky_planet_count: usize,
+}
+
+pub fn create_score_report(game_desc: &GameDesc) -> ScoreReport {
+    let galaxy = create_galaxy(game_desc);
+    let mut report = ScoreReport {
+        seed: galaxy.seed,
+        unipolar_amount: 0.0,
+        gas_giant_count: 0,
+        ice_giant_count: 0,
+        ob_star_count: 0,
+        total_luminosity: 0.0,
+        rocky_planet_count: 0,
+    };
+    for sp in &galaxy.stars {
+        let star = &sp.star;
+        report.unipolar_amount += sp.get_avg_vein(&VeinType::Mag);
+        report.total_luminosity += star.get_luminosity();
+        if matches!(star.get_spectr(), SpectrType::O | SpectrType::B) {
+            report.ob_star_count += 1;
+        }
+        for planet in sp.get_planets() {
+            if !planet.is_gas_giant() {
+                report.rocky_planet_count += 1;
+            } else if planet.get_theme().temperature < 0.0 {
+                report.ice_giant_count += 1;
+            } else {
+                report.gas_giant_count += 1;
+            }
+        }
+    }
+    report
+}
@@ -53,6 +53,8 @@ export enum RuleType {
     BirthDistance = "BirthDistance", // 12
     XDistance = "XDistance", // 13
     SpectrDistance = "SpectrDistance", // 14
+    NearestDistance = "NearestDistance", // 15+
+    BirthPlanet = "BirthPlanet", // 16
     Luminosity = "Luminosity", // 20
     Spectr = "Spectr", // 21
     DysonRadius = "DysonRadius", // 22
@@ -60,9 +62,12 @@ export enum RuleType {
     SatelliteCount = "SatelliteCount", // 31
     TidalLockCount = "TidalLockCount", // 33
     PlanetInDysonCount = "PlanetInDysonCount", // 34
+    SingularityCount = "SingularityCount", // 35
     ThemeId = "ThemeId", // 40
     GasCount = "GasCount", // 41 / 32
+    GiantCount = "GiantCount", // 41 / 32
     OceanType = "OceanType", // 42
+    GalaxyThemeCount = "GalaxyThemeCount", // 43
     GasRate = "GasRate", // 50
     AverageVeinAmount = "AverageVeinAmount", // 51
 }
@@ -96,3 +101,20 @@ export enum OceanType {
     Water = 1000,
     Sulfur = 1116,
 }
+
+export enum Singularity {
+    TidalLocked = "TidalLocked",
+    TidalLocked2 = "TidalLocked2",
+    TidalLocked4 = "TidalLocked4",
+    ClockwiseRotate = "ClockwiseRotate",
+    LaySide = "LaySide",
+    MultipleSatellites = "MultipleSatellites",
+}
+
+export enum BirthPlanetTargetType {
+    Vein = "Vein",
+    OceanType = "OceanType",
+    Wind = "Wind",
+    Luminosity = "Luminosity",
+    SystemOil = "SystemOil",
+}
@@ -26,6 +26,9 @@ const Header: Component = () => {
                 <A href="/galaxy" class={styles.button}>
                     星系查看器
                 </A>
+                <A href="/watch-job" class={styles.button}>
+                    任务查看器
+                </A>
             </div>
             <div class={styles.icons}>
                 <a
@@ -1,4 +1,4 @@
-import { JSX } from "solid-js"
+import { JSX, Show } from "solid-js"
 import Input from "../components/Input"
 import styles from "./ProgressEditor.module.css"
 import StarCountSelector from "./StarCountSelector"
@@ -161,6 +161,24 @@ function ProgressEditor<E extends ProfileProgressInfo>(props: {
                     秒
                 </div>
             </div>
+            <Show when={props.nativeMode}>
+                <div class={styles.field}>
+                    <div class={styles.label}>
+                        <Tooltip text="本机程序会记住已找到的种子，再次搜索相同的规则时不再报告。">
+                            跳过已找到的种子
+                        </Tooltip>
+                    </div>
+                    <div class={styles.input}>
+                        <Toggle
+                            value={props.progress.dedupe ?? false}
+                            onChange={(value) =>
+                                props.onProgressChange("dedupe", value)
+                            }
+                            disabled={props.searching}
+                        />
+                    </div>
+                </div>
+            </Show>
         </div>
     )
 }
@@ -1,16 +1,18 @@
 import { Component, Index, Match, Show, Switch } from "solid-js"
 import {
+    BirthPlanetTargetType,
     ConditionType,
     GasType,
     OceanType,
     RuleType,
+    Singularity,
     SpectrType,
     StarType,
     VeinType,
 } from "../enums"
 import styles from "./RuleEditor.module.css"
 import Select from "../components/Select"
-import { IoTrash } from "solid-icons/io"
+import { IoAdd, IoTrash } from "solid-icons/io"
 import Button from "../components/Button"
 import NumberInput from "../components/NumberInput"
 import { conditionTypeNames, planetTypes, veinNames } from "../util"
@@ -623,6 +625,340 @@ const EditThemeId: Component<{
     )
 }
 
+type GiantKind = "total" | "gas" | "ice"
+
+const giantKinds: GiantKind[] = ["total", "gas", "ice"]
+
+const giantKindNames: Record<GiantKind, string> = {
+    total: "巨行星",
+    gas: "气态巨行星",
+    ice: "冰巨行星",
+}
+
+const EditGiantCount: Component<{
+    value: Rule.GiantCount
+    onChange: (value: Rule.GiantCount) => void
+    disabled?: boolean
+}> = (props) => {
+    // only the counts the user added are sent, the others are not checked
+    const kinds = () => giantKinds.filter((kind) => !!props.value[kind])
+    const nextKind = () => giantKinds.find((kind) => !props.value[kind])
+    const setCondition = (kind: GiantKind, condition?: Condition) => {
+        const value = { ...props.value }
+        if (condition) {
+            value[kind] = condition
+        } else {
+            delete value[kind]
+        }
+        props.onChange(value)
+    }
+    const setKind = (from: GiantKind, to: GiantKind) => {
+        const value = { ...props.value, [to]: props.value[from] }
+        delete value[from]
+        props.onChange(value)
+    }
+    return (
+        <>
+            含有{" "}
+            <Index each={kinds()}>
+                {(kind, index) => (
+                    <>
+                        <Show when={index > 0}>，且 </Show>
+                        <ConditionTypeSelector
+                            value={props.value[kind()]!}
+                            onChange={(condition) =>
+                                setCondition(kind(), condition)
+                            }
+                            disabled={props.disabled}
+                        />{" "}
+                        <ConditionValueInput
+                            class={styles.inputCount}
+                            value={props.value[kind()]!}
+                            onChange={(condition) =>
+                                setCondition(kind(), condition)
+                            }
+                            emptyValue={-1}
+                            error={props.value[kind()]!.value < 0}
+                            disabled={props.disabled}
+                        />{" "}
+                        <Select
+                            class={styles.selectGiantKind}
+                            value={kind()}
+                            onChange={(to) => setKind(kind(), to)}
+                            options={giantKinds.filter(
+                                (other) =>
+                                    other === kind() || !props.value[other],
+                            )}
+                            getLabel={(kind) => giantKindNames[kind]}
+                            disabled={props.disabled}
+                        />
+                        <Show when={!props.disabled && kinds().length > 1}>
+                            <DeleteButton
+                                onDelete={() => setCondition(kind())}
+                            />
+                        </Show>{" "}
+                    </>
+                )}
+            </Index>
+            <Show when={!props.disabled && nextKind() !== undefined}>
+                <div
+                    class={styles.addTheme}
+                    onClick={() =>
+                        setCondition(nextKind()!, {
+                            type: ConditionType.Gte,
+                            value: 1,
+                        })
+                    }
+                >
+                    <IoAdd />
+                </div>
+            </Show>
+        </>
+    )
+}
+
+const EditSingularityCount: Component<{
+    value: Rule.SingularityCount
+    onChange: (value: Rule.SingularityCount) => void
+    disabled?: boolean
+}> = (props) => {
+    const condition = () => props.value.condition
+    const setCondition = (condition: Condition) =>
+        props.onChange({ ...props.value, condition })
+    return (
+        <>
+            含有{" "}
+            <ConditionTypeSelector
+                value={condition()}
+                onChange={setCondition}
+                disabled={props.disabled}
+            />{" "}
+            <ConditionValueInput
+                class={styles.inputCount}
+                value={condition()}
+                onChange={setCondition}
+                emptyValue={-1}
+                error={condition().value <= 0}
+                disabled={props.disabled}
+            />{" "}
+            <Select
+                class={styles.selectSingularity}
+                value={props.value.singularities[0]}
+                onChange={(singularity) =>
+                    props.onChange({
+                        ...props.value,
+                        singularities: [singularity],
+                    })
+                }
+                options={singularities}
+                getLabel={(singularity) => singularityNames[singularity]}
+                disabled={props.disabled}
+            />{" "}
+            的行星
+        </>
+    )
+}
+
+const EditNearestDistance: Component<{
+    value: Rule.NearestDistance
+    onChange: (value: Rule.NearestDistance) => void
+    disabled?: boolean
+}> = (props) => {
+    const condition = () => props.value.condition
+    const setCondition = (condition: Condition) =>
+        props.onChange({ ...props.value, condition })
+    return (
+        <>
+            离出生星系最近的{" "}
+            <Select
+                class={styles.selectNearestRule}
+                value={props.value.rule}
+                isSelected={(rule) => isSameNearestRule(rule, props.value.rule)}
+                onChange={(rule) => props.onChange({ ...props.value, rule })}
+                options={nearestRules}
+                getLabel={(rule) =>
+                    isNearestRule(rule) ? nearestRuleLabel(rule) : "其他条件"
+                }
+                disabled={props.disabled}
+            />{" "}
+            距离{" "}
+            <ConditionTypeSelector
+                value={condition()}
+                onChange={setCondition}
+                disabled={props.disabled}
+            />{" "}
+            <ConditionValueInput
+                class={styles.inputDistance}
+                value={condition()}
+                onChange={setCondition}
+                emptyValue={-1}
+                error={condition().value <= 0}
+                disabled={props.disabled}
+            />{" "}
+            光年（没有此类恒星时视为无限远）
+        </>
+    )
+}
+
+const EditBirthPlanet: Component<{
+    value: Rule.BirthPlanet
+    onChange: (value: Rule.BirthPlanet) => void
+    disabled?: boolean
+}> = (props) => {
+    const condition = () => props.value.condition
+    const setCondition = (condition: Condition) =>
+        props.onChange({ ...props.value, condition })
+    const target = () => props.value.target
+    const isOil = () =>
+        target().type === BirthPlanetTargetType.SystemOil ||
+        (target().type === BirthPlanetTargetType.Vein &&
+            (target() as { value: VeinType }).value === VeinType.Oil)
+    return (
+        <>
+            出生行星的{" "}
+            <Select
+                class={styles.selectBirthPlanetTarget}
+                value={target()}
+                isSelected={(t) =>
+                    t.type === target().type &&
+                    (t as { value?: VeinType }).value ===
+                        (target() as { value?: VeinType }).value
+                }
+                onChange={(target) => {
+                    const condition =
+                        target.type === BirthPlanetTargetType.OceanType
+                            ? { type: ConditionType.Eq, value: OceanType.Water }
+                            : props.value.condition
+                    props.onChange({ ...props.value, target, condition })
+                }}
+                options={birthPlanetTargets}
+                getLabel={(t) =>
+                    t.type === BirthPlanetTargetType.Vein
+                        ? veinNames[t.value]
+                        : birthPlanetTargetNames[t.type]
+                }
+                disabled={props.disabled}
+            />{" "}
+            <Show
+                when={target().type === BirthPlanetTargetType.OceanType}
+                fallback={
+                    <>
+                        <ConditionTypeSelector
+                            value={condition()}
+                            onChange={setCondition}
+                            disabled={props.disabled}
+                        />{" "}
+                        <ConditionValueInput
+                            class={styles.inputVein}
+                            value={condition()}
+                            onChange={setCondition}
+                            emptyValue={-1}
+                            error={condition().value < 0}
+                            disabled={props.disabled}
+                        />
+                        {isOil() ? " /s" : " "}
+                    </>
+                }
+            >
+                是{" "}
+                <Select
+                    class={styles.selectOcean}
+                    value={condition().value}
+                    onChange={(value) =>
+                        setCondition({ type: ConditionType.Eq, value })
+                    }
+                    options={oceans}
+                    getLabel={(oceanType) =>
+                        oceanType === OceanType.Water ? "水" : "硫酸"
+                    }
+                    disabled={props.disabled}
+                />
+            </Show>
+        </>
+    )
+}
+
+const EditGalaxyThemeCount: Component<{
+    value: Rule.GalaxyThemeCount
+    onChange: (value: Rule.GalaxyThemeCount) => void
+    disabled?: boolean
+}> = (props) => {
+    const condition = () => props.value.condition
+    const setCondition = (condition: Condition) =>
+        props.onChange({ ...props.value, condition })
+    const setThemeIds = (themeIds: number[]) =>
+        props.onChange({ ...props.value, themeIds })
+    // the next theme that is not in the group yet
+    const nextThemeId = () =>
+        themeIds.find((themeId) => !props.value.themeIds.includes(themeId))
+    return (
+        <>
+            整个星区中{" "}
+            <Index each={props.value.themeIds}>
+                {(themeId, index) => (
+                    <>
+                        <Show when={index > 0}>或 </Show>
+                        <Select
+                            class={styles.selectPlanetType}
+                            value={themeId()}
+                            onChange={(value) =>
+                                setThemeIds(
+                                    props.value.themeIds.map((id, i) =>
+                                        i === index ? value : id,
+                                    ),
+                                )
+                            }
+                            options={themeIds}
+                            getLabel={(themeId) => planetTypes[themeId]!}
+                            disabled={props.disabled}
+                        />
+                        <Show
+                            when={
+                                !props.disabled &&
+                                props.value.themeIds.length > 1
+                            }
+                        >
+                            <DeleteButton
+                                onDelete={() =>
+                                    setThemeIds(
+                                        props.value.themeIds.filter(
+                                            (_, i) => i !== index,
+                                        ),
+                                    )
+                                }
+                            />
+                        </Show>{" "}
+                    </>
+                )}
+            </Index>
+            <Show when={!props.disabled && nextThemeId() !== undefined}>
+                <div
+                    class={styles.addTheme}
+                    onClick={() =>
+                        setThemeIds([...props.value.themeIds, nextThemeId()!])
+                    }
+                >
+                    <IoAdd />
+                </div>{" "}
+            </Show>
+            行星数量{" "}
+            <ConditionTypeSelector
+                value={condition()}
+                onChange={setCondition}
+                disabled={props.disabled}
+            />{" "}
+            <ConditionValueInput
+                class={styles.inputCount}
+                value={condition()}
+                onChange={setCondition}
+                emptyValue={-1}
+                error={condition().value < 0}
+                disabled={props.disabled}
+            />
+        </>
+    )
+}
+
 function isType<T extends SimpleRule, K extends RuleType>(
     rule: T,
     type: K,
@@ -692,6 +1028,25 @@ const EditSimpleRule: Component<{
             <Match when={isType(props.value, RuleType.ThemeId)}>
                 {(value) => <EditThemeId {...props} value={value()} />}
             </Match>
+            <Match when={isType(props.value, RuleType.GiantCount)}>
+                {(value) => <EditGiantCount {...props} value={value()} />}
+            </Match>
+            <Match when={isType(props.value, RuleType.SingularityCount)}>
+                {(value) => (
+                    <EditSingularityCount {...props} value={value()} />
+                )}
+            </Match>
+            <Match when={isType(props.value, RuleType.NearestDistance)}>
+                {(value) => <EditNearestDistance {...props} value={value()} />}
+            </Match>
+            <Match when={isType(props.value, RuleType.BirthPlanet)}>
+                {(value) => <EditBirthPlanet {...props} value={value()} />}
+            </Match>
+            <Match when={isType(props.value, RuleType.GalaxyThemeCount)}>
+                {(value) => (
+                    <EditGalaxyThemeCount {...props} value={value()} />
+                )}
+            </Match>
             <Match when={isType(props.value, RuleType.Birth)}>
                 <div class={styles.birth}>是出生星系</div>
             </Match>
@@ -877,6 +1232,11 @@ const ruleNames: Record<RuleType, string> = {
     [RuleType.GasRate]: "气体产量",
     [RuleType.AverageVeinAmount]: "矿脉储量",
     [RuleType.PlanetInDysonCount]: "戴森球内行星数量",
+    [RuleType.GiantCount]: "气态/冰巨行星数量",
+    [RuleType.SingularityCount]: "特殊行星数量",
+    [RuleType.NearestDistance]: "最近恒星距离",
+    [RuleType.BirthPlanet]: "出生行星",
+    [RuleType.GalaxyThemeCount]: "星区行星主题数量",
 }
 
 const rules: SimpleRule[] = [
@@ -994,6 +1354,51 @@ const rules: SimpleRule[] = [
             value: 0,
         },
     },
+    {
+        type: RuleType.GiantCount,
+        total: {
+            type: ConditionType.Gte,
+            value: 1,
+        },
+    },
+    {
+        type: RuleType.SingularityCount,
+        singularities: [Singularity.TidalLocked],
+        condition: {
+            type: ConditionType.Gte,
+            value: 1,
+        },
+    },
+    {
+        type: RuleType.NearestDistance,
+        rule: {
+            type: RuleType.Spectr,
+            spectr: [SpectrType.O],
+        },
+        condition: {
+            type: ConditionType.Lte,
+            value: 0,
+        },
+    },
+    {
+        type: RuleType.BirthPlanet,
+        target: {
+            type: BirthPlanetTargetType.Vein,
+            value: VeinType.Titanium,
+        },
+        condition: {
+            type: ConditionType.Gte,
+            value: 0,
+        },
+    },
+    {
+        type: RuleType.GalaxyThemeCount,
+        themeIds: [1],
+        condition: {
+            type: ConditionType.Gte,
+            value: 1,
+        },
+    },
 ]
 
 const veins: VeinType[] = [
@@ -1026,6 +1431,85 @@ const spectrs: SpectrType[] = [
 
 const oceans: OceanType[] = [OceanType.Water, OceanType.Sulfur]
 
+type NearestRule = Rule.Spectr | Rule.AverageVeinAmount
+
+// stars that the nearest distance can be measured to
+const nearestRules: NearestRule[] = [
+    ...spectrs.map(
+        (spectr): NearestRule => ({ type: RuleType.Spectr, spectr: [spectr] }),
+    ),
+    ...veins.map(
+        (vein): NearestRule => ({
+            type: RuleType.AverageVeinAmount,
+            vein,
+            condition: { type: ConditionType.Gt, value: 0 },
+        }),
+    ),
+]
+
+function isNearestRule(rule: Rule): rule is NearestRule {
+    return (
+        (rule.type === RuleType.Spectr && rule.spectr.length === 1) ||
+        (rule.type === RuleType.AverageVeinAmount &&
+            rule.condition.type === ConditionType.Gt &&
+            rule.condition.value === 0)
+    )
+}
+
+function isSameNearestRule(a: Rule, b: Rule) {
+    if (!isNearestRule(a) || !isNearestRule(b) || a.type !== b.type) {
+        return false
+    }
+    return a.type === RuleType.Spectr
+        ? a.spectr[0] === (b as Rule.Spectr).spectr[0]
+        : a.vein === (b as Rule.AverageVeinAmount).vein
+}
+
+function nearestRuleLabel(rule: NearestRule) {
+    return rule.type === RuleType.Spectr
+        ? `${rule.spectr[0]} 型恒星`
+        : `有${veinNames[rule.vein]}的恒星`
+}
+
+const singularities: Singularity[] = [
+    Singularity.TidalLocked,
+    Singularity.TidalLocked2,
+    Singularity.TidalLocked4,
+    Singularity.ClockwiseRotate,
+    Singularity.LaySide,
+    Singularity.MultipleSatellites,
+]
+
+const singularityNames: Record<Singularity, string> = {
+    [Singularity.TidalLocked]: "潮汐锁定",
+    [Singularity.TidalLocked2]: "轨道共振1:2",
+    [Singularity.TidalLocked4]: "轨道共振1:4",
+    [Singularity.ClockwiseRotate]: "反向自转",
+    [Singularity.LaySide]: "横躺自转",
+    [Singularity.MultipleSatellites]: "多卫星",
+}
+
+const birthPlanetTargets: BirthPlanetTarget[] = [
+    ...veins.map(
+        (value): BirthPlanetTarget => ({
+            type: BirthPlanetTargetType.Vein,
+            value,
+        }),
+    ),
+    { type: BirthPlanetTargetType.OceanType },
+    { type: BirthPlanetTargetType.Wind },
+    { type: BirthPlanetTargetType.Luminosity },
+    { type: BirthPlanetTargetType.SystemOil },
+]
+
+const birthPlanetTargetNames: Record<BirthPlanetTargetType, string> = {
+    [BirthPlanetTargetType.Vein]: "矿脉储量",
+    [BirthPlanetTargetType.OceanType]: "海洋",
+    [BirthPlanetTargetType.Wind]: "风能效率",
+    [BirthPlanetTargetType.Luminosity]: "光能效率",
+    [BirthPlanetTargetType.SystemOil]: "星系内其他行星原油",
+}
+
 const starTypes: StarType[] = [
     StarType.MainSeqStar,
     StarType.GiantStar,
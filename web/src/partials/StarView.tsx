@@ -93,6 +93,21 @@ function planetGases(planet: Planet): Gas[] {
         .map((type) => [type, veins[type]])
 }
 
+function combineVeinStats(stats: VeinStat[]): VeinStat[] {
+    const veins: Record<VeinType, VeinStat> = {} as any
+    for (const stat of stats) {
+        const existing = veins[stat.veinType]
+        if (existing) {
+            existing.min += stat.min
+            existing.max += stat.max
+            existing.avg += stat.avg
+        } else {
+            veins[stat.veinType] = { ...stat }
+        }
+    }
+    return veinOrder.map((type) => veins[type]).filter((x) => x)
+}
+
 function formatVein(amount: number, isOil: boolean): string {
     if (isOil) {
         return formatNumber(amount * 4e-5, 2) + " /s"
@@ -276,6 +291,75 @@ const StarVeins: Component<{ star: Star }> = (props) => (
     </>
 )
 
+const BirthPlanetView: Component<{ star: Star; birthPlanet: BirthPlanet }> = (
+    props,
+) => (
+    <>
+        <div class={styles.row}>
+            <div class={styles.field}>行星</div>
+            <div class={styles.value}>
+                {props.star.name} {romans[props.birthPlanet.index]}
+            </div>
+        </div>
+        <div class={styles.row}>
+            <div class={styles.field}>类型</div>
+            <div class={styles.value}>
+                {planetTypes[props.birthPlanet.themeId] ||
+                    props.birthPlanet.themeId}
+            </div>
+        </div>
+        <div class={styles.row}>
+            <div class={styles.field}>海洋</div>
+            <div class={styles.value}>
+                {props.birthPlanet.oceanType === OceanType.Water
+                    ? "水"
+                    : props.birthPlanet.oceanType === OceanType.Sulfur
+                      ? "硫酸"
+                      : "无"}
+            </div>
+        </div>
+        <div class={styles.row}>
+            <div class={styles.field}>风能强度</div>
+            <div class={styles.value}>
+                {toPrecision(props.birthPlanet.wind * 100, 0)}%
+            </div>
+        </div>
+        <div class={styles.row}>
+            <div class={styles.field}>太阳能强度</div>
+            <div class={styles.value}>
+                {toPrecision(props.birthPlanet.luminosity * 100, 0)}%
+            </div>
+        </div>
+        <For each={combineVeinStats(props.birthPlanet.veins)}>
+            {(vein) => {
+                const isOil = vein.veinType === VeinType.Oil
+                return (
+                    <div class={styles.row}>
+                        <div class={styles.field}>
+                            {veinNames[vein.veinType]}
+                        </div>
+                        <div class={styles.value}>
+                            {formatVein(vein.min, isOil)} -{" "}
+                            {formatVein(vein.max, isOil)}（平均{" "}
+                            {formatVein(vein.avg, isOil)}）
+                        </div>
+                    </div>
+                )
+            }}
+        </For>
+        <div class={styles.row}>
+            <div class={styles.field}>
+                <Tooltip text="出生星系中其他行星的原油平均产量之和">
+                    星系原油
+                </Tooltip>
+            </div>
+            <div class={styles.value}>
+                {formatVein(props.birthPlanet.systemOil, true)}
+            </div>
+        </div>
+    </>
+)
+
 const NearbyStar: Component<{
     seed: integer
     star: Star
@@ -471,6 +555,23 @@ const StarView: Component<{
                         </div>
                         <StarVeins star={props.star} />
                     </div>
+                    <Show
+                        when={
+                            props.star.index === 0 && props.galaxy?.birthPlanet
+                        }
+                    >
+                        {(birthPlanet) => (
+                            <div class={styles.card}>
+                                <div class={styles.title}>
+                                    <span>出生行星</span>
+                                </div>
+                                <BirthPlanetView
+                                    star={props.star}
+                                    birthPlanet={birthPlanet()}
+                                />
+                            </div>
+                        )}
+                    </Show>
                 </div>
                 <Show when={!!props.galaxy}>
                     <div class={styles.column}>
@@ -8,6 +8,8 @@ import type {
     GasType,
     OceanType as EOceanType,
     CompositeRuleType,
+    Singularity,
+    BirthPlanetTargetType,
 } from "./enums"
 
 declare global {
@@ -23,6 +25,17 @@ declare global {
     declare interface Galaxy {
         seed: integer
         stars: Star[]
+        birthPlanet?: BirthPlanet
+    }
+
+    declare interface BirthPlanet {
+        index: integer
+        themeId: integer
+        oceanType: EOceanType
+        wind: float
+        luminosity: float
+        veins: VeinStat[]
+        systemOil: float
     }
 
     declare type Position = [x: float, y: float, z: float]
@@ -106,6 +119,10 @@ declare global {
         | Condition.Gt
         | Condition.Gte
 
+    declare type BirthPlanetTarget =
+        | { type: BirthPlanetTargetType.Vein; value: VeinType }
+        | { type: Exclude<BirthPlanetTargetType, BirthPlanetTargetType.Vein> }
+
     declare namespace Rule {
         export type None = { type: RuleType.None }
         export type And = { type: RuleType.And; rules: Rule[] }
@@ -185,6 +202,32 @@ declare global {
             includeGiant: boolean
             condition: Condition
         }
+        export type GiantCount = {
+            type: RuleType.GiantCount
+            total?: Condition
+            gas?: Condition
+            ice?: Condition
+        }
+        export type SingularityCount = {
+            type: RuleType.SingularityCount
+            singularities: Singularity[]
+            condition: Condition
+        }
+        export type NearestDistance = {
+            type: RuleType.NearestDistance
+            rule: Rule
+            condition: Condition
+        }
+        export type BirthPlanet = {
+            type: RuleType.BirthPlanet
+            target: BirthPlanetTarget
+            condition: Condition
+        }
+        export type GalaxyThemeCount = {
+            type: RuleType.GalaxyThemeCount
+            themeIds: integer[]
+            condition: Condition
+        }
 
         export type Composite = {
             type: CompositeRuleType.Composite
@@ -222,6 +265,11 @@ declare global {
         | Rule.SpectrDistance
         | Rule.GasRate
         | Rule.PlanetInDysonCount
+        | Rule.GiantCount
+        | Rule.SingularityCount
+        | Rule.NearestDistance
+        | Rule.BirthPlanet
+        | Rule.GalaxyThemeCount
 
     declare type CompoundRule = Rule.And | Rule.Or
 
@@ -238,13 +286,22 @@ declare global {
         rule: Rule | CompositeRule
         concurrency: integer
         autosave: integer
+        /** Skip seeds the native server already found for this search */
+        dedupe?: boolean
         onError?: (error?: any) => void
         onResult?: (result: FindResult) => void
+        /** Id of the job on the native server, to follow it from elsewhere */
+        onStarted?: (jobId: integer) => void
         onProgress?: (current: number) => void
         onComplete?: () => void
         onInterrupt?: () => void
     }
 
+    declare type SubscribeOptions = Pick<
+        FindOptions,
+        "onError" | "onResult" | "onProgress" | "onComplete" | "onInterrupt"
+    >
+
     declare interface WorldGen {
         generate(gameDesc: GameDesc): Promise<Galaxy>
         find(options: FindOptions): void
@@ -281,6 +338,7 @@ declare global {
         resourceMultiplier: float
         autosave: float
         concurrency: integer
+        dedupe?: boolean
         start: integer
         end: integer
         current: integer
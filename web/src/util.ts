@@ -1,4 +1,5 @@
 import {
+    BirthPlanetTargetType,
     CompositeRuleType,
     ConditionType,
     GasType,
@@ -42,6 +43,17 @@ function fixRule(rule: SimpleRule): SimpleRule {
             condition: modifyCondition(rule.condition, (value) => value * 25e3),
         }
     }
+    if (
+        rule.type === RuleType.BirthPlanet &&
+        (rule.target.type === BirthPlanetTargetType.SystemOil ||
+            (rule.target.type === BirthPlanetTargetType.Vein &&
+                rule.target.value === VeinType.Oil))
+    ) {
+        return {
+            ...rule,
+            condition: modifyCondition(rule.condition, (value) => value * 25e3),
+        }
+    }
     if (rule.type === RuleType.XDistance) {
         return {
             ...rule,
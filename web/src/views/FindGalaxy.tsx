@@ -146,6 +146,7 @@ const FindGalaxy: Component = () => {
     const [store, setStore] = useStore()
     const [currentPage, setCurrentPage] = createSignal(1)
     const [tick, setTick] = createSignal(0)
+    const [jobId, setJobId] = createSignal<number | null>(null)
     const isLoaded = () => !!profile()
     const hasProgress = () =>
         progress.start > -1 && progress.current > progress.start
@@ -271,6 +272,7 @@ const FindGalaxy: Component = () => {
     async function onStartSearching() {
         await onSaveProfile()
         setStore("searching", true)
+        setJobId(null)
         let results: FindResult[] = []
         getWorldGen(nativeMode()).find({
             gameDesc: {
@@ -280,7 +282,9 @@ const FindGalaxy: Component = () => {
             range: [Math.max(progress.start, progress.current), progress.end],
             concurrency: progress.concurrency,
             autosave: progress.autosave,
+            dedupe: progress.dedupe,
             rule: constructMultiRule(unwrap(progress.multiRules)),
+            onStarted: setJobId,
             onResult: (result) => {
                 console.debug("result", result)
                 results.push(result)
@@ -383,6 +387,11 @@ const FindGalaxy: Component = () => {
                             total={progress.end - progress.start}
                         />
                     </Show>
+                    <Show when={store.searching && jobId() !== null}>
+                        <div class={styles.progressText}>
+                            任务编号：{jobId()}
+                        </div>
+                    </Show>
                 </div>
                 <Show when={hasProgress()}>
                     <Button onClick={() => setExportModal(true)}>导出</Button>
@@ -213,6 +213,7 @@ const FindStar: Component = () => {
     const [store, setStore] = useStore()
     const [currentPage, setCurrentPage] = createSignal(1)
     const [tick, setTick] = createSignal(0)
+    const [jobId, setJobId] = createSignal<number | null>(null)
     const isLoaded = () => !!profile()
     const hasProgress = () =>
         progress.start > -1 && progress.current > progress.start
@@ -333,6 +334,7 @@ const FindStar: Component = () => {
     async function onStartSearching() {
         await onSaveProfile()
         setStore("searching", true)
+        setJobId(null)
         let results: FindResult[] = []
         getWorldGen(nativeMode()).find({
             gameDesc: {
@@ -342,7 +344,9 @@ const FindStar: Component = () => {
             range: [Math.max(progress.start, progress.current), progress.end],
             concurrency: progress.concurrency,
             autosave: progress.autosave,
+            dedupe: progress.dedupe,
             rule: constructRule(unwrap(progress.rules)),
+            onStarted: setJobId,
             onResult: (result) => {
                 console.debug("result", result)
                 results.push(result)
@@ -445,6 +449,11 @@ const FindStar: Component = () => {
                             total={progress.end - progress.start}
                         />
                     </Show>
+                    <Show when={store.searching && jobId() !== null}>
+                        <div class={styles.progressText}>
+                            任务编号：{jobId()}
+                        </div>
+                    </Show>
                 </div>
                 <Show when={hasProgress()}>
                     <Button onClick={() => setExportModal(true)}>导出</Button>
@@ -0,0 +1,154 @@
+import styles from "./WatchJob.module.css"
+import { A, useNavigate, useParams } from "@solidjs/router"
+import {
+    Component,
+    For,
+    Show,
+    batch,
+    createSignal,
+    onCleanup,
+    onMount,
+} from "solid-js"
+import NumberInput from "../components/NumberInput"
+import Button from "../components/Button"
+import { getNativeWorldGen } from "../worldgen"
+import { useStore } from "../store"
+import { getSearch } from "../util"
+import StarCountSelector from "../partials/StarCountSelector"
+import ResourceMultiplierSelector from "../partials/ResourceMultiplerSelector"
+
+type Status = "idle" | "watching" | "completed" | "interrupted"
+
+const WatchJob: Component = () => {
+    const [store, setStore] = useStore()
+    const params = useParams()
+    const navigate = useNavigate()
+    const [jobId, setJobId] = createSignal<number>(Number(params.jobId) || -1)
+    const [status, setStatus] = createSignal<Status>("idle")
+    const [error, setError] = createSignal("")
+    const [current, setCurrent] = createSignal(-1)
+    const [results, setResults] = createSignal<FindResult[]>([])
+    let unsubscribe = () => {}
+
+    onCleanup(() => unsubscribe())
+
+    function isValueValid() {
+        const v = jobId()
+        return Number.isInteger(v) && v > 0
+    }
+
+    function watch() {
+        unsubscribe()
+        batch(() => {
+            setStatus("watching")
+            setError("")
+            setCurrent(-1)
+            setResults([])
+        })
+        unsubscribe = getNativeWorldGen().subscribe(jobId(), {
+            onResult: (result) => setResults((prev) => [...prev, result]),
+            onProgress: (end) => setCurrent((c) => Math.max(c, end)),
+            onComplete: () => setStatus("completed"),
+            onInterrupt: () => setStatus("interrupted"),
+            onError: (err) => {
+                batch(() => {
+                    setStatus("interrupted")
+                    setError(String(err?.message ?? err))
+                })
+            },
+        })
+    }
+
+    function handleSubmit(ev: Event) {
+        ev.preventDefault()
+        if (!isValueValid()) return
+        navigate(`/watch-job/${jobId()}`, { replace: true })
+        watch()
+    }
+
+    onMount(() => {
+        if (isValueValid()) {
+            watch()
+        }
+    })
+
+    function buildUrl(result: FindResult) {
+        return `/galaxy/${result.seed}/${result.indexes[0] ?? 0}${getSearch({
+            count: store.settings.view.starCount,
+            multipler: store.settings.view.resourceMultipler,
+        })}`
+    }
+
+    return (
+        <div class={styles.content}>
+            <form class={styles.search} onSubmit={handleSubmit}>
+                <div class={styles.searchTitle}>任务编号：</div>
+                <div class={styles.searchRow}>
+                    <NumberInput
+                        class={styles.searchInput}
+                        value={jobId()}
+                        onChange={setJobId}
+                        emptyValue={-1}
+                    />
+                    <Button type="submit" disabled={!isValueValid()}>
+                        查看
+                    </Button>
+                </div>
+                <div class={styles.searchTitle}>恒星数量：</div>
+                <StarCountSelector
+                    class={styles.searchInput}
+                    value={store.settings.view.starCount}
+                    onChange={(v) =>
+                        setStore("settings", "view", "starCount", v)
+                    }
+                />
+                <div class={styles.searchTitle}>资源倍率：</div>
+                <ResourceMultiplierSelector
+                    class={styles.searchInput}
+                    value={store.settings.view.resourceMultipler}
+                    onChange={(v) =>
+                        setStore("settings", "view", "resourceMultipler", v)
+                    }
+                />
+            </form>
+            <Show when={status() !== "idle"}>
+                <div class={styles.progress}>
+                    <Show when={current() > -1} fallback="等待进度…">
+                        已搜索至：{current()}
+                    </Show>
+                    <span>找到：{results().length}</span>
+                    <Show when={status() === "completed"}>
+                        <span>已完成！</span>
+                    </Show>
+                    <Show when={status() === "interrupted"}>
+                        <span class={styles.error}>
+                            {error() || "连接已断开"}
+                        </span>
+                    </Show>
+                </div>
+                <div class={styles.results}>
+                    <For each={results()}>
+                        {(result) => (
+                            <A
+                                href={buildUrl(result)}
+                                target="_blank"
+                                class={styles.result}
+                            >
+                                <span>
+                                    {String(result.seed).padStart(8, "0")}
+                                </span>
+                                <span class={styles.resultIndex}>
+                                    {result.indexes
+                                        .map((index) => `#${index + 1}`)
+                                        .join(" ")}
+                                </span>
+                            </A>
+                        )}
+                    </For>
+                </div>
+            </Show>
+        </div>
+    )
+}
+
+export default WatchJob
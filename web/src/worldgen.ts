@@ -2,8 +2,13 @@ import { WorldGenBrowser } from "./worldgen/browser"
 import { WorldGenNative } from "./worldgen/native"
 
 const browser: WorldGen = new WorldGenBrowser()
-const native: WorldGen = new WorldGenNative()
+const native = new WorldGenNative()
 
 export function getWorldGen(nativeMode: boolean): WorldGen {
     return nativeMode ? native : browser
 }
+
+/** Only the native program can follow searches started elsewhere */
+export function getNativeWorldGen(): WorldGenNative {
+    return native
+}
@@ -11,8 +11,47 @@ function waitConnect(ws: WebSocket) {
     })
 }
 
+interface NativeConfig {
+    host: string
+    port: number
+    token?: string
+}
+
+// Devices on the local network open the page with ?token=, and with
+// ?server=<host>:<port> when the native program does not serve the page. Read
+// them before the router changes the URL
+const pageParams = new URLSearchParams(location.search)
+const pageToken = pageParams.get("token")
+const pageServer = pageParams.get("server")
+
+// Served by the native program next to the page, missing on the hosted page
+async function loadConfig(): Promise<NativeConfig> {
+    try {
+        const res = await fetch(import.meta.env.BASE_URL + "config.json")
+        if (res.ok) {
+            const { port, token } = await res.json()
+            return { host: location.hostname, port, token }
+        }
+    } catch (err) {
+        console.debug("no native config", err)
+    }
+    if (pageServer) {
+        const url = new URL(`ws://${pageServer}`)
+        return { host: url.hostname, port: Number(url.port) || 62879 }
+    }
+    return { host: "127.0.0.1", port: 62879 }
+}
+
+let config: Promise<NativeConfig> | undefined
+
 async function connect() {
-    const ws = new WebSocket("ws://127.0.0.1:62879")
+    config ??= loadConfig()
+    const { host, port, token = pageToken } = await config
+    const url = new URL(`ws://${host}:${port}`)
+    if (token) {
+        url.searchParams.set("token", token)
+    }
+    const ws = new WebSocket(url)
     console.debug("connecting")
     await waitConnect(ws)
     console.debug("connected")
@@ -24,9 +63,14 @@ export class WorldGenNative implements WorldGen {
 
     async generate(gameDesc: GameDesc): Promise<Galaxy> {
         const ws = await connect()
-        const promise = new Promise<Galaxy>((resolve) => {
+        const promise = new Promise<Galaxy>((resolve, reject) => {
             ws.addEventListener("message", (ev) => {
-                resolve(JSON.parse(ev.data))
+                const msg = JSON.parse(ev.data)
+                if (msg.type === "Error") {
+                    reject(new Error(msg.message))
+                } else {
+                    resolve(msg)
+                }
                 ws.close()
             })
         })
@@ -40,8 +84,10 @@ export class WorldGenNative implements WorldGen {
         rule,
         concurrency,
         autosave,
+        dedupe,
         onError,
         onResult,
+        onStarted,
         onProgress,
         onComplete,
         onInterrupt,
@@ -64,9 +110,11 @@ export class WorldGenNative implements WorldGen {
 
                 ws.addEventListener("message", (ev) => {
                     const msg = JSON.parse(ev.data)
-                    if (msg.type === "Result") {
+                    if (msg.type === "Started") {
+                        onStarted?.(msg.jobId)
+                    } else if (msg.type === "Result") {
                         onResult?.({ seed: msg.seed, indexes: msg.indexes })
-                    } else {
+                    } else if (msg.type === "Progress" || msg.type === "Done") {
                         onProgress?.(msg.end)
                         if (msg.type === "Done") {
                             done = true
@@ -83,6 +131,7 @@ export class WorldGenNative implements WorldGen {
                         rule,
                         concurrency,
                         autosave,
+                        dedupe,
                     }),
                 )
             })
@@ -93,4 +142,58 @@ export class WorldGenNative implements WorldGen {
         this._stop()
         this._stop = () => {}
     }
+
+    /** Follows a search by its job id, returns a function to stop following it */
+    subscribe(
+        jobId: integer,
+        {
+            onError,
+            onResult,
+            onProgress,
+            onComplete,
+            onInterrupt,
+        }: SubscribeOptions,
+    ): () => void {
+        let closed = false
+        let socket: WebSocket | undefined
+        connect()
+            .then((ws) => {
+                if (closed) {
+                    ws.close()
+                    return
+                }
+                socket = ws
+                let done = false
+
+                ws.addEventListener("close", () => {
+                    if (!done && !closed) {
+                        onInterrupt?.()
+                    }
+                })
+
+                ws.addEventListener("message", (ev) => {
+                    const msg = JSON.parse(ev.data)
+                    if (msg.type === "Error") {
+                        done = true
+                        onError?.(new Error(msg.message))
+                        ws.close()
+                    } else if (msg.type === "Result") {
+                        onResult?.({ seed: msg.seed, indexes: msg.indexes })
+                    } else if (msg.type === "Progress" || msg.type === "Done") {
+                        onProgress?.(msg.end)
+                        if (msg.type === "Done") {
+                            done = true
+                            onComplete?.()
+                            ws.close()
+                        }
+                    }
+                })
+                ws.send(JSON.stringify({ type: "Subscribe", jobId }))
+            })
+            .catch((err) => onError?.(err))
+        return () => {
+            closed = true
+            socket?.close()
+        }
+    }
 }